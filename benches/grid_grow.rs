@@ -0,0 +1,58 @@
+//! Drawing a large set of random walls into a growing map, in the spirit of day 14, where the map
+//! grows in every direction.
+
+use aoc2022::bench::bench_fn;
+use aoc2022::{BoundsBehavior, CharMap, Pos2};
+
+/// Walls to draw, every wall is a horizontal or vertical line.
+const WALLS: usize = 20_000;
+
+/// Walls are spread over a square of this size, centered at zero.
+const SPREAD: isize = 4_000;
+
+const REPEATS: usize = 5;
+
+/// Deterministic xorshift generator, so every run draws the same walls.
+struct Random(u64);
+
+impl Random {
+  fn next(&mut self, range: isize) -> isize {
+    self.0 ^= self.0 << 13;
+    self.0 ^= self.0 >> 7;
+    self.0 ^= self.0 << 17;
+    (self.0 % range as u64) as isize
+  }
+}
+
+fn main() {
+  let mut random = Random(0x2022_1214);
+  let walls = (0..WALLS)
+    .map(|_| {
+      let start = Pos2::new(random.next(SPREAD) - SPREAD / 2, random.next(SPREAD) - SPREAD / 2);
+      let len = random.next(50);
+      let end = if random.next(2) == 0 {
+        Pos2::new(start.x + len, start.y)
+      } else {
+        Pos2::new(start.x, start.y + len)
+      };
+      (start, end)
+    })
+    .collect::<Vec<_>>();
+
+  let stats = bench_fn(REPEATS, || {
+    let mut map = CharMap::empty(BoundsBehavior::grow(b'.'));
+    for (start, end) in &walls {
+      for pos in start.line_to(*end) {
+        map[pos] = b'#';
+      }
+    }
+    map
+  });
+  println!(
+    "draw {} walls: min {:?}, median {:?}, max {:?}",
+    WALLS,
+    stats.min(),
+    stats.median(),
+    stats.max()
+  );
+}
@@ -0,0 +1,206 @@
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+
+use crate::{BoundsBehavior, Dir2, Grid, Pos2};
+
+/// Cellular automaton updating all cells of a grid at once by the rule, which computes the next
+/// value of the cell from the current state of the grid.
+pub struct Automaton<T, F> {
+  grid: Grid<T>,
+  rule_fn: F,
+  /// Offsets of the cells the rule reads, enables the active cells mode.
+  neighborhood: Option<Vec<Dir2>>,
+  /// Cells to evaluate in the next step, every cell if `None`.
+  active: Option<Vec<Pos2>>,
+  /// Cells already added to the next active cells.
+  queued: Grid<bool>,
+  generation: usize,
+  hash_fn: Option<fn(&Grid<T>) -> u64>,
+  /// Generations of every state seen, by the state hash.
+  history: HashMap<u64, Vec<usize>>,
+  /// State the repeat detection started from, replayed to confirm the repeats hashes point at.
+  snapshot: Option<(usize, Grid<T>)>,
+  repeat: Option<Repeat>,
+}
+
+/// State of the automaton repeating with the given period since the given generation.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Repeat {
+  pub first: usize,
+  pub period: usize,
+}
+
+impl Repeat {
+  /// Earlier generation with the same state as the given one.
+  pub fn equivalent(&self, generation: usize) -> usize {
+    if generation < self.first {
+      generation
+    } else {
+      self.first + (generation - self.first) % self.period
+    }
+  }
+}
+
+fn state_hash<T: Clone + Hash>(grid: &Grid<T>) -> u64 {
+  let mut hasher = DefaultHasher::new();
+  grid.top_left().hash(&mut hasher);
+  grid.bottom_right().hash(&mut hasher);
+  for pos in grid.every_pos() {
+    grid[pos].hash(&mut hasher);
+  }
+  hasher.finish()
+}
+
+impl<T: Clone + PartialEq, F: Fn(&Grid<T>, Pos2) -> T> Automaton<T, F> {
+  pub fn new(grid: Grid<T>, rule_fn: F) -> Self {
+    Automaton {
+      queued: grid.map(|_| false),
+      grid,
+      rule_fn,
+      neighborhood: None,
+      active: None,
+      generation: 0,
+      hash_fn: None,
+      history: HashMap::new(),
+      snapshot: None,
+      repeat: None,
+    }
+  }
+
+  /// Only evaluate the cells which could have changed: the cells reading any cell changed in the
+  /// previous step. The rule must read no other cells than the cell itself and the given offsets
+  /// from it.
+  pub fn with_active_cells(mut self, neighborhood: &[Dir2]) -> Self {
+    self.neighborhood = Some(neighborhood.to_vec());
+    self
+  }
+
+  /// Remember the hash of every state to detect when the states start repeating, see `repeat`.
+  /// States with the same hash are confirmed cell by cell, replaying the steps from the state the
+  /// detection started from. Hashing visits every cell, so it takes away the benefit of the active
+  /// cells mode.
+  pub fn with_repeat_detection(mut self) -> Self
+  where
+    T: Hash,
+  {
+    self.hash_fn = Some(state_hash::<T>);
+    self.snapshot = Some((self.generation, self.grid.clone()));
+    self
+      .history
+      .entry(state_hash(&self.grid))
+      .or_default()
+      .push(self.generation);
+    self
+  }
+
+  pub fn grid(&self) -> &Grid<T> {
+    &self.grid
+  }
+
+  pub fn into_grid(self) -> Grid<T> {
+    self.grid
+  }
+
+  /// Amount of steps made so far.
+  pub fn generation(&self) -> usize {
+    self.generation
+  }
+
+  /// First repeat of the states detected, if repeat detection is enabled.
+  pub fn repeat(&self) -> Option<Repeat> {
+    self.repeat
+  }
+
+  /// Make a single step, returns if any cell has changed.
+  pub fn step(&mut self) -> bool {
+    let mut changes = Vec::new();
+    let mut evaluate = |pos: Pos2| {
+      let updated = (self.rule_fn)(&self.grid, pos);
+      if updated != self.grid[pos] {
+        changes.push((pos, updated));
+      }
+    };
+    match self.active {
+      Some(ref active) => active.iter().for_each(|pos| evaluate(*pos)),
+      None => self.grid.every_pos().for_each(evaluate),
+    }
+    for (pos, updated) in &changes {
+      self.grid[*pos] = updated.clone();
+    }
+
+    if let Some(ref neighborhood) = self.neighborhood {
+      let mut active = Vec::new();
+      for (pos, _) in &changes {
+        for dir in neighborhood.iter().chain(&[Dir2::new(0, 0)]) {
+          // The rule reads the cells by indexing, which wraps around the wrapping grids only
+          let reader = *pos + -*dir;
+          let reader = match self.grid.bounds() {
+            BoundsBehavior::Wrap => Some(self.grid.wrap_pos(reader)),
+            _ => Some(reader).filter(|reader| self.grid.is_in_bounds(*reader)),
+          };
+          if let Some(reader) = reader.filter(|reader| !self.queued[*reader]) {
+            self.queued[reader] = true;
+            active.push(reader);
+          }
+        }
+      }
+      for pos in &active {
+        self.queued[*pos] = false;
+      }
+      self.active = Some(active);
+    }
+
+    self.generation += 1;
+    if let Some(hash_fn) = self.hash_fn.filter(|_| self.repeat.is_none()) {
+      let hash = hash_fn(&self.grid);
+      let candidates = self.history.get(&hash).cloned().unwrap_or_default();
+      let seen = candidates.into_iter().find(|generation| {
+        let state = self.replay(*generation);
+        state.top_left() == self.grid.top_left() && state.same_cells(&self.grid)
+      });
+      match seen {
+        Some(first) => {
+          self.repeat = Some(Repeat {
+            first,
+            period: self.generation - first,
+          })
+        }
+        None => self.history.entry(hash).or_default().push(self.generation),
+      }
+    }
+    !changes.is_empty()
+  }
+
+  /// State at the given generation, replayed from the snapshot by evaluating every cell.
+  fn replay(&self, generation: usize) -> Grid<T> {
+    let (start, ref snapshot) = *self.snapshot.as_ref().unwrap();
+    let mut grid = snapshot.clone();
+    for _ in start..generation {
+      let changes: Vec<_> = grid
+        .every_pos()
+        .filter_map(|pos| Some((pos, (self.rule_fn)(&grid, pos))).filter(|(pos, updated)| *updated != grid[*pos]))
+        .collect();
+      for (pos, updated) in changes {
+        grid[pos] = updated;
+      }
+    }
+    grid
+  }
+
+  /// Make up to the given amount of steps, stopping early once the grid stops changing. Returns
+  /// the amount of steps which changed the grid.
+  pub fn run(&mut self, steps: usize) -> usize {
+    (0..steps).take_while(|_| self.step()).count()
+  }
+
+  /// Make steps until the grid stops changing or, if repeat detection is enabled, until the
+  /// states start repeating. Returns the amount of steps which changed the grid.
+  pub fn run_until_stable(&mut self) -> usize {
+    let mut changed = 0;
+    while self.repeat.is_none() && self.step() {
+      changed += 1;
+    }
+    changed
+  }
+}
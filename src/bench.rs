@@ -0,0 +1,95 @@
+use crate::{Day, ParseError};
+use serde::Serialize;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+/// Timing statistics of a single stage over all repetitions, in nanoseconds.
+#[derive(Clone, Copy, Debug, Serialize)]
+pub struct Stats {
+  pub min_ns: u64,
+  pub median_ns: u64,
+  pub max_ns: u64,
+}
+
+impl Stats {
+  fn from_samples(mut samples: Vec<Duration>) -> Stats {
+    samples.sort();
+    let nanos = |d: Duration| d.as_nanos() as u64;
+    Stats {
+      min_ns: nanos(samples[0]),
+      median_ns: nanos(samples[samples.len() / 2]),
+      max_ns: nanos(samples[samples.len() - 1]),
+    }
+  }
+
+  pub fn min(&self) -> Duration {
+    Duration::from_nanos(self.min_ns)
+  }
+
+  pub fn median(&self) -> Duration {
+    Duration::from_nanos(self.median_ns)
+  }
+
+  pub fn max(&self) -> Duration {
+    Duration::from_nanos(self.max_ns)
+  }
+}
+
+/// Run the function `repeats` times, timing every run.
+pub fn bench_fn<T>(repeats: usize, mut f: impl FnMut() -> T) -> Stats {
+  assert!(repeats > 0, "at least one repetition is required");
+  Stats::from_samples((0..repeats).map(|_| time(&mut f).1).collect())
+}
+
+/// Timings of parsing and both parts of a day for a single input.
+#[derive(Clone, Debug, Serialize)]
+pub struct DayBench {
+  pub day: usize,
+  pub input: String,
+  pub repeats: usize,
+  pub parse: Stats,
+  /// Not measured if the part was not requested.
+  pub part1: Option<Stats>,
+  pub part2: Option<Stats>,
+}
+
+fn time<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+  let start = Instant::now();
+  let result = black_box(f());
+  (result, start.elapsed())
+}
+
+/// Run the day `repeats` times, timing parsing, part 1 and part 2 separately. Every repetition
+/// parses the input anew, so parts never share any state between repetitions.
+pub fn bench_day(
+  day: Day,
+  name: &str,
+  input: &str,
+  repeats: usize,
+  part1: bool,
+  part2: bool,
+) -> Result<DayBench, ParseError> {
+  assert!(repeats > 0, "at least one repetition is required");
+  let mut parse_samples = Vec::with_capacity(repeats);
+  let mut part1_samples = Vec::with_capacity(repeats);
+  let mut part2_samples = Vec::with_capacity(repeats);
+  for _ in 0..repeats {
+    let (solution, elapsed) = time(|| day.parse(black_box(input)));
+    let solution = solution?;
+    parse_samples.push(elapsed);
+    if part1 {
+      part1_samples.push(time(|| solution.part1()).1);
+    }
+    if part2 {
+      part2_samples.push(time(|| solution.part2()).1);
+    }
+  }
+  Ok(DayBench {
+    day: day.day,
+    input: name.to_owned(),
+    repeats,
+    parse: Stats::from_samples(parse_samples),
+    part1: Some(part1_samples).filter(|s| !s.is_empty()).map(Stats::from_samples),
+    part2: Some(part2_samples).filter(|s| !s.is_empty()).map(Stats::from_samples),
+  })
+}
@@ -0,0 +1,375 @@
+use aoc2022::bench::{bench_day, DayBench, Stats};
+use aoc2022::days::{self, DAYS};
+use aoc2022::visualize::{
+  record_log, visualize, visualize_terminal, Channel, EventLog, FrameTrigger, RecordFormat, Recorder,
+};
+use aoc2022::*;
+use std::path::Path;
+use std::str::FromStr;
+use std::sync::mpsc;
+use std::time::Duration;
+
+const USAGE: &str = "usage: aoc [--day N] [--part 1|2] [--inputs DIR] [--input FILE|-] [--bench N [--json FILE]]
+           [--replay LOG [--speed X] [--seek SECONDS]] [--visualize | --terminal | --log LOG |
+           --record DIR|FILE.gif [--frame-sleeps N|--frame-events N] [--scale N]]";
+
+struct Args {
+  /// Day to run, all days if not given.
+  day: Option<usize>,
+  /// Part to run, both parts if not given.
+  part: Option<usize>,
+  /// Directory with the inputs of all days.
+  inputs: InputDir,
+  /// Name of the input file in the day directory, `-` for the standard input.
+  input: String,
+  /// Draw the progress in a window (only for a single day).
+  visualize: bool,
+  /// Draw the progress in the terminal (only for a single day).
+  terminal: bool,
+  /// Record the progress into PNG files in the directory or into an animated GIF (only for a single day).
+  record: Option<String>,
+  /// When to take the recorded frames, on every sleep if not given.
+  trigger: Option<FrameTrigger>,
+  /// Size of a single map position in the recorded frames.
+  scale: Option<usize>,
+  /// Record the drawing events into the log file (only for a single day).
+  log: Option<String>,
+  /// Replay the drawing events from the log file instead of solving.
+  replay: Option<String>,
+  /// Replay speed factor.
+  speed: Option<f64>,
+  /// Time of the recording to start the replay at.
+  seek: Option<Duration>,
+  /// Benchmark every stage over this many repetitions instead of printing the answers.
+  bench: Option<usize>,
+  /// File to write the benchmark results to, as JSON.
+  json: Option<String>,
+}
+
+fn next_num<T: FromStr>(it: &mut impl Iterator<Item = String>, flag: &str) -> Result<T, String> {
+  let value = it.next().ok_or_else(|| format!("{} requires a value", flag))?;
+  value
+    .parse()
+    .map_err(|_| format!("{} expects a number, got '{}'", flag, value))
+}
+
+fn parse_args() -> Result<Args, String> {
+  let mut args = Args {
+    day: None,
+    part: None,
+    inputs: InputDir::resolve(None),
+    input: "input.txt".to_owned(),
+    visualize: false,
+    terminal: false,
+    record: None,
+    trigger: None,
+    scale: None,
+    log: None,
+    replay: None,
+    speed: None,
+    seek: None,
+    bench: None,
+    json: None,
+  };
+  let mut it = std::env::args().skip(1);
+  while let Some(arg) = it.next() {
+    match arg.as_str() {
+      "--day" => {
+        let day = next_num(&mut it, &arg)?;
+        if days::find(day).is_none() {
+          return Err(format!("no solution for day {}", day));
+        }
+        args.day = Some(day);
+      }
+      "--part" => {
+        let part = next_num(&mut it, &arg)?;
+        if part != 1 && part != 2 {
+          return Err(format!("part must be 1 or 2, got {}", part));
+        }
+        args.part = Some(part);
+      }
+      "--inputs" => {
+        let dir = it.next().ok_or("--inputs requires a value")?;
+        args.inputs = InputDir::resolve(Some(Path::new(&dir)));
+      }
+      "--input" => args.input = it.next().ok_or("--input requires a value")?,
+      "--visualize" => args.visualize = true,
+      "--terminal" => args.terminal = true,
+      "--record" => args.record = Some(it.next().ok_or("--record requires a value")?),
+      "--frame-sleeps" | "--frame-events" => {
+        let count = next_num(&mut it, &arg)?;
+        if count == 0 {
+          return Err(format!("{} must be positive", arg));
+        }
+        args.trigger = Some(if arg == "--frame-sleeps" {
+          FrameTrigger::Sleeps(count)
+        } else {
+          FrameTrigger::Events(count)
+        });
+      }
+      "--scale" => {
+        let scale = next_num(&mut it, &arg)?;
+        if scale == 0 {
+          return Err("--scale must be positive".to_owned());
+        }
+        args.scale = Some(scale);
+      }
+      "--log" => args.log = Some(it.next().ok_or("--log requires a value")?),
+      "--replay" => args.replay = Some(it.next().ok_or("--replay requires a value")?),
+      "--speed" => {
+        let speed: f64 = next_num(&mut it, &arg)?;
+        if speed <= 0.0 {
+          return Err("--speed must be positive".to_owned());
+        }
+        args.speed = Some(speed);
+      }
+      "--seek" => {
+        let seek: f64 = next_num(&mut it, &arg)?;
+        args.seek = Some(Duration::try_from_secs_f64(seek).map_err(|_| format!("invalid --seek {}", seek))?);
+      }
+      "--bench" => {
+        let repeats = next_num(&mut it, &arg)?;
+        if repeats == 0 {
+          return Err("--bench requires at least one repetition".to_owned());
+        }
+        args.bench = Some(repeats);
+      }
+      "--json" => args.json = Some(it.next().ok_or("--json requires a value")?),
+      _ => return Err(format!("unexpected argument '{}'", arg)),
+    }
+  }
+  let visual_modes = [args.visualize, args.terminal, args.record.is_some(), args.log.is_some()];
+  if visual_modes.iter().any(|&mode| mode) && args.day.is_none() && args.replay.is_none() {
+    return Err("--visualize, --terminal, --record and --log require --day or --replay".to_owned());
+  }
+  if visual_modes.iter().filter(|&&mode| mode).count() > 1 {
+    return Err("only one of --visualize, --terminal, --record and --log can be given".to_owned());
+  }
+  if (args.speed.is_some() || args.seek.is_some()) && args.replay.is_none() {
+    return Err("--speed and --seek require --replay".to_owned());
+  }
+  if args.replay.is_some() && args.day.is_some() {
+    return Err("--replay cannot be combined with --day".to_owned());
+  }
+  if (args.trigger.is_some() || args.scale.is_some()) && args.record.is_none() {
+    return Err("--frame-sleeps, --frame-events and --scale require --record".to_owned());
+  }
+  if args.json.is_some() && args.bench.is_none() {
+    return Err("--json requires --bench".to_owned());
+  }
+  if (visual_modes.iter().any(|&mode| mode) || args.replay.is_some()) && args.bench.is_some() {
+    return Err("--bench cannot be combined with the visualization".to_owned());
+  }
+  if args.input == STDIN_INPUT && args.day.is_none() {
+    return Err("reading the input from stdin requires --day".to_owned());
+  }
+  Ok(args)
+}
+
+fn print_answer(day: usize, input: &str, part: usize, answer: &str) {
+  if answer.contains('\n') {
+    println!("day{:02} {} part {}:\n{}", day, input, part, answer.trim_end());
+  } else {
+    println!("day{:02} {} part {}: {}", day, input, part, answer);
+  }
+}
+
+/// Solve the day while drawing the progress through the channel.
+fn solve_visual(day: Day, data: &str, channel: &Channel) -> Result<(String, String), ParseError> {
+  Ok(day.parse(data)?.visualize(channel))
+}
+
+fn print_visual(day: Day, input: &str, result: Result<(String, String), ParseError>) -> bool {
+  match result {
+    Ok((first, second)) => {
+      print_answer(day.day, input, 1, &first);
+      print_answer(day.day, input, 2, &second);
+      true
+    }
+    Err(err) => {
+      eprintln!("day{:02} {}: {}", day.day, input, err);
+      false
+    }
+  }
+}
+
+/// Run the worker in the visualization backend selected on the command line. Returns `false` if
+/// the backend has failed.
+fn run_visual(args: &Args, title: &str, worker: impl FnOnce(Channel) + Send + 'static) -> bool {
+  if args.terminal {
+    if let Err(err) = visualize_terminal(worker) {
+      eprintln!("cannot draw in the terminal: {}", err);
+      return false;
+    }
+  } else if let Some(ref path) = args.record {
+    let mut recorder = Recorder::new(RecordFormat::from_path(path)).with_scale(args.scale.unwrap_or(1));
+    if let Some(trigger) = args.trigger {
+      recorder = recorder.with_trigger(trigger);
+    }
+    match recorder.record(worker) {
+      Ok(frames) => eprintln!("recorded {} frames into '{}'", frames, path),
+      Err(err) => {
+        eprintln!("cannot record into '{}': {}", path, err);
+        return false;
+      }
+    }
+  } else if let Some(ref path) = args.log {
+    match record_log(Path::new(path), worker) {
+      Ok(events) => eprintln!("recorded {} events into '{}'", events, path),
+      Err(err) => {
+        eprintln!("cannot record into '{}': {}", path, err);
+        return false;
+      }
+    }
+  } else {
+    visualize(title, worker);
+  }
+  true
+}
+
+fn format_stats(stats: &Stats) -> String {
+  format!(
+    "{:>12} {:>12} {:>12}",
+    format!("{:.2?}", stats.min()),
+    format!("{:.2?}", stats.median()),
+    format!("{:.2?}", stats.max())
+  )
+}
+
+fn print_bench(bench: &DayBench) {
+  let stages = [
+    ("parse", Some(&bench.parse)),
+    ("part 1", bench.part1.as_ref()),
+    ("part 2", bench.part2.as_ref()),
+  ];
+  for (stage, stats) in stages {
+    if let Some(stats) = stats {
+      println!(
+        "day{:02} {:<10} {:<6} {}",
+        bench.day,
+        bench.input,
+        stage,
+        format_stats(stats)
+      );
+    }
+  }
+}
+
+fn write_json(path: &str, benches: &[DayBench]) -> std::io::Result<()> {
+  let json = serde_json::to_string_pretty(benches).map_err(std::io::Error::other)?;
+  std::fs::write(path, json + "\n")
+}
+
+fn main() {
+  let args = match parse_args() {
+    Ok(args) => args,
+    Err(err) => {
+      eprintln!("{}\n{}", err, USAGE);
+      std::process::exit(2);
+    }
+  };
+
+  if let Some(ref path) = args.replay {
+    let log = match EventLog::load(Path::new(path)) {
+      Ok(log) => log,
+      Err(err) => {
+        eprintln!("cannot read '{}': {}", path, err);
+        std::process::exit(1);
+      }
+    };
+    let (speed, seek) = (args.speed.unwrap_or(1.0), args.seek.unwrap_or_default());
+    let ok = run_visual(&args, path, move |channel| log.replay(&channel, speed, seek));
+    std::process::exit(if ok { 0 } else { 1 });
+  }
+
+  if args.visualize || args.terminal || args.record.is_some() || args.log.is_some() {
+    let day = days::find(args.day.unwrap()).unwrap();
+    let data = match args.inputs.load(day.day, &args.input) {
+      Ok(data) => data,
+      Err(err) => {
+        eprintln!("{}", err);
+        std::process::exit(1);
+      }
+    };
+    // The window never returns, so the worker prints the answers itself. Other backends print them
+    // once done, so the answers do not get mixed with the drawing in the terminal.
+    let input = args.input.clone();
+    let print_in_worker = args.visualize;
+    let (sender, receiver) = mpsc::channel();
+    let ok = run_visual(&args, &format!("day{:02} {}", day.day, args.input), move |channel| {
+      let result = solve_visual(day, &data, &channel);
+      if print_in_worker {
+        print_visual(day, &input, result);
+      } else {
+        sender.send(result).unwrap();
+      }
+    });
+    let solved = receiver
+      .try_recv()
+      .is_ok_and(|result| print_visual(day, &args.input, result));
+    std::process::exit(if ok && solved { 0 } else { 1 });
+  }
+
+  if args.bench.is_some() {
+    println!(
+      "{:<5} {:<10} {:<6} {:>12} {:>12} {:>12}",
+      "day", "input", "stage", "min", "median", "max"
+    );
+  }
+
+  let mut failed = false;
+  let mut benches = Vec::new();
+  for day in DAYS.iter().filter(|d| args.day.is_none_or(|day| d.day == day)) {
+    let data = match args.inputs.load(day.day, &args.input) {
+      Ok(data) => data,
+      Err(err) => {
+        eprintln!("day{:02}: {}", day.day, err);
+        failed = true;
+        continue;
+      }
+    };
+    if let Some(repeats) = args.bench {
+      match bench_day(
+        *day,
+        &args.input,
+        &data,
+        repeats,
+        args.part != Some(2),
+        args.part != Some(1),
+      ) {
+        Ok(bench) => {
+          print_bench(&bench);
+          benches.push(bench);
+        }
+        Err(err) => {
+          eprintln!("day{:02} {}: {}", day.day, args.input, err);
+          failed = true;
+        }
+      }
+      continue;
+    }
+    let solution = match day.parse(&data) {
+      Ok(solution) => solution,
+      Err(err) => {
+        eprintln!("day{:02} {}: {}", day.day, args.input, err);
+        failed = true;
+        continue;
+      }
+    };
+    if args.part != Some(2) {
+      print_answer(day.day, &args.input, 1, &solution.part1());
+    }
+    if args.part != Some(1) {
+      print_answer(day.day, &args.input, 2, &solution.part2());
+    }
+  }
+  if let Some(ref path) = args.json {
+    if let Err(err) = write_json(path, &benches) {
+      eprintln!("cannot write '{}': {}", path, err);
+      failed = true;
+    }
+  }
+  if failed {
+    std::process::exit(1);
+  }
+}
@@ -0,0 +1,121 @@
+use crate::visualize::{Channel, Color};
+use crate::{Area, Grid, Pos2, MOVES_4};
+
+/// Connected regions of a grid, see `Grid::components`.
+#[derive(Clone)]
+pub struct Components {
+  /// Index of the component every position belongs to, `None` for positions not in any.
+  pub labels: Grid<Option<usize>>,
+  pub components: Vec<Component>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Component {
+  /// First position of the component, left to right, then top to bottom.
+  pub seed: Pos2,
+  /// Amount of positions in the component.
+  pub size: usize,
+  /// Smallest area covering the component.
+  pub area: Area,
+  /// Amount of sides of the positions which do not touch the component.
+  pub perimeter: usize,
+}
+
+impl Components {
+  pub fn len(&self) -> usize {
+    self.components.len()
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.components.is_empty()
+  }
+
+  /// Component the position belongs to.
+  pub fn component(&self, pos: Pos2) -> Option<&Component> {
+    self.labels[pos].map(|label| &self.components[label])
+  }
+
+  /// Draw every component in its own color, leaving the other positions black.
+  pub fn draw(&self, channel: &Channel) {
+    channel.draw_grid(&self.labels, |label| label.map_or((0, 0, 0), label_color));
+  }
+}
+
+/// Some bright color, different for the neighbouring labels.
+fn label_color(label: usize) -> Color {
+  let hash = label.wrapping_mul(0x9e37_79b9) >> 8;
+  (
+    0x40 + (hash & 0xbf) as u8,
+    0x40 + ((hash >> 8) & 0xbf) as u8,
+    0x40 + ((hash >> 16) & 0xbf) as u8,
+  )
+}
+
+impl<T: Clone + PartialEq> Grid<T> {
+  /// Positions reachable from the seed moving in 4 directions over the positions the predicate is
+  /// true for, in the order they were reached. Empty if the seed itself is not passable.
+  pub fn flood_fill(&self, seed: Pos2, passable_fn: impl Fn(&Self, Pos2) -> bool) -> Vec<Pos2> {
+    if !self.is_in_bounds(seed) || !passable_fn(self, seed) {
+      return Vec::new();
+    }
+    let mut seen = self.map(|_| false);
+    seen[seed] = true;
+    let mut filled = vec![seed];
+    let mut idx = 0;
+    while idx < filled.len() {
+      let pos = filled[idx];
+      idx += 1;
+      for next in self.neighbors(pos) {
+        if !seen[next] && passable_fn(self, next) {
+          seen[next] = true;
+          filled.push(next);
+        }
+      }
+    }
+    filled
+  }
+
+  /// Label the regions of positions connected in 4 directions. The class function tells which
+  /// region kind the cell belongs to, neighbouring cells of the same class are in the same region.
+  /// Cells without the class are not labelled.
+  pub fn components<K: PartialEq>(&self, class_fn: impl Fn(&T) -> Option<K>) -> Components {
+    let mut labels: Grid<Option<usize>> = self.map(|_| None);
+    let mut components = Vec::new();
+    for seed in self.every_pos() {
+      if labels[seed].is_some() {
+        continue;
+      }
+      let Some(class) = class_fn(&self[seed]) else {
+        continue;
+      };
+      let label = components.len();
+      let same = |pos: Option<Pos2>| pos.is_some_and(|pos| class_fn(&self[pos]).as_ref() == Some(&class));
+      let mut component = Component {
+        seed,
+        size: 0,
+        area: Area::new(seed, seed),
+        perimeter: 0,
+      };
+      labels[seed] = Some(label);
+      let mut stack = vec![seed];
+      while let Some(pos) = stack.pop() {
+        component.size += 1;
+        component.area.top_left.x = component.area.top_left.x.min(pos.x);
+        component.area.top_left.y = component.area.top_left.y.min(pos.y);
+        component.area.bottom_right.x = component.area.bottom_right.x.max(pos.x);
+        component.area.bottom_right.y = component.area.bottom_right.y.max(pos.y);
+        for dir in MOVES_4 {
+          let next = self.neighbor(pos, dir);
+          if !same(next) {
+            component.perimeter += 1;
+          } else if let Some(next) = next.filter(|next| labels[*next].is_none()) {
+            labels[next] = Some(label);
+            stack.push(next);
+          }
+        }
+      }
+      components.push(component);
+    }
+    Components { labels, components }
+  }
+}
@@ -0,0 +1,195 @@
+use std::collections::HashMap;
+
+use crate::{CharMap, Dir2, Dir3, ParseError, Pos2, Pos3, MOVES_4};
+
+/// Cube folded from a net of six square faces drawn on a map, like the day 22 map.
+#[derive(Clone, Debug)]
+pub struct CubeNet {
+  size: isize,
+  faces: Vec<Face>,
+  /// Position and facing after stepping over the edge of the net, for every cell on the edge and
+  /// every facing leading off the net.
+  wraps: HashMap<(Pos2, Dir2), (Pos2, Dir2)>,
+}
+
+/// Face of the cube with the orientation it got folded into. The cube is centered at the origin,
+/// every face points outwards with its normal.
+#[derive(Clone, Copy, Debug)]
+struct Face {
+  top_left: Pos2,
+  normal: Dir3,
+  /// Direction of the net X axis on the face.
+  right: Dir3,
+  /// Direction of the net Y axis on the face.
+  down: Dir3,
+}
+
+impl Face {
+  fn dir_3d(&self, dir: Dir2) -> Dir3 {
+    add(scale(self.right, dir.dx), scale(self.down, dir.dy))
+  }
+
+  fn dir_2d(&self, dir: Dir3) -> Dir2 {
+    Dir2::new(dot(self.right, dir), dot(self.down, dir))
+  }
+
+  /// Orientation of the neighbouring face of the net, folded over the edge in the direction.
+  fn fold(&self, dir: Dir2) -> Face {
+    let edge = self.dir_3d(dir);
+    let mut face = Face {
+      top_left: Pos2::new(self.top_left.x + dir.dx, self.top_left.y + dir.dy),
+      normal: edge,
+      ..*self
+    };
+    if dir.dx != 0 {
+      face.right = scale(self.normal, -dir.dx);
+    } else {
+      face.down = scale(self.normal, -dir.dy);
+    }
+    face
+  }
+}
+
+fn scale(dir: Dir3, factor: isize) -> Dir3 {
+  Dir3::new(dir.dx * factor, dir.dy * factor, dir.dz * factor)
+}
+
+fn add(a: Dir3, b: Dir3) -> Dir3 {
+  Dir3::new(a.dx + b.dx, a.dy + b.dy, a.dz + b.dz)
+}
+
+fn dot(a: Dir3, b: Dir3) -> isize {
+  a.dx * b.dx + a.dy * b.dy + a.dz * b.dz
+}
+
+impl CubeNet {
+  /// Find the six faces among the non-blank cells of the map and fold them into a cube. Works for
+  /// any of the 11 nets of the cube, in any orientation.
+  pub fn fold(map: &CharMap, blank: u8) -> Result<CubeNet, ParseError> {
+    let on_net = |pos: Pos2| map.is_in_bounds(pos) && map[pos] != blank;
+    let cells = map.every_pos().filter(|pos| on_net(*pos)).count() as isize;
+    let size = (1..).find(|size| 6 * size * size >= cells).unwrap();
+    if 6 * size * size != cells {
+      return Err(ParseError::new(
+        format!("{} cells cannot make six square faces", cells),
+        "",
+      ));
+    }
+
+    // Faces are found on the net scaled down by the face size, then scaled back
+    let dims = map.dims();
+    let mut tiles = Vec::new();
+    for y in 0..(dims.y + size - 1) / size {
+      for x in 0..(dims.x + size - 1) / size {
+        let corner = map.top_left() + Pos2::new(x * size, y * size);
+        if !on_net(corner) {
+          continue;
+        }
+        let far_corner = corner + Pos2::new(size - 1, size - 1);
+        if let Some(pos) = Pos2::iter_rect(corner, far_corner).find(|pos| !on_net(*pos)) {
+          return Err(ParseError::new(
+            format!("{} is not on a face of size {}", pos, size),
+            "",
+          ));
+        }
+        tiles.push(Pos2::new(x, y));
+      }
+    }
+    if tiles.len() != 6 {
+      return Err(ParseError::new("faces are not aligned to a grid", ""));
+    }
+
+    // Fold the faces on the scaled down net starting from the first one
+    let mut faces = vec![Face {
+      top_left: tiles[0],
+      normal: Dir3::new(0, 0, -1),
+      right: Dir3::new(1, 0, 0),
+      down: Dir3::new(0, 1, 0),
+    }];
+    let mut idx = 0;
+    while idx < faces.len() {
+      let face = faces[idx];
+      idx += 1;
+      for dir in MOVES_4 {
+        let folded = face.fold(dir);
+        if !tiles.contains(&folded.top_left) || faces.iter().any(|other| other.top_left == folded.top_left) {
+          continue;
+        }
+        if faces.iter().any(|other| other.normal == folded.normal) {
+          return Err(ParseError::new("faces overlap when folded, not a cube net", ""));
+        }
+        faces.push(folded);
+      }
+    }
+    if faces.len() != 6 {
+      return Err(ParseError::new("faces are not connected, not a cube net", ""));
+    }
+    faces.sort_by_key(|face| (face.top_left.y, face.top_left.x));
+    for face in &mut faces {
+      face.top_left = map.top_left() + Pos2::new(face.top_left.x * size, face.top_left.y * size);
+    }
+
+    let mut cube = CubeNet {
+      size,
+      faces,
+      wraps: HashMap::new(),
+    };
+    for face in &cube.faces {
+      for dir in MOVES_4 {
+        let edge = Pos2::iter_rect(face.top_left, face.top_left + Pos2::new(size - 1, size - 1))
+          .filter(|pos| !on_net(*pos + dir));
+        for pos in edge {
+          let wrap = cube.cross_edge(face, pos, dir);
+          cube.wraps.insert((pos, dir), wrap);
+        }
+      }
+    }
+    Ok(cube)
+  }
+
+  /// Position of the cell center on the cube, doubled to keep it integer. Edges of the cube are
+  /// `2 * size` long.
+  fn cell_3d(&self, face: &Face, pos: Pos2) -> Pos3 {
+    let offset = Pos2::new(
+      2 * (pos.x - face.top_left.x) + 1 - self.size,
+      2 * (pos.y - face.top_left.y) + 1 - self.size,
+    );
+    Pos3::zero() + scale(face.normal, self.size) + face.dir_3d(Dir2::new(offset.x, offset.y))
+  }
+
+  /// Position and facing after stepping from the cell on the edge of the face over that edge.
+  fn cross_edge(&self, face: &Face, pos: Pos2, dir: Dir2) -> (Pos2, Dir2) {
+    let edge = face.dir_3d(dir);
+    let next = self.faces.iter().find(|other| other.normal == edge).unwrap();
+    // Over the edge, the cell moves by one in the direction of the edge and by one into the cube
+    let cell = self.cell_3d(face, pos) + edge + -face.normal;
+    let cell = Dir3::new(cell.x, cell.y, cell.z);
+    let next_pos = next.top_left
+      + Pos2::new(
+        (dot(next.right, cell) + self.size - 1) / 2,
+        (dot(next.down, cell) + self.size - 1) / 2,
+      );
+    (next_pos, next.dir_2d(-face.normal))
+  }
+
+  /// Length of the edges of the faces.
+  pub fn size(&self) -> isize {
+    self.size
+  }
+
+  /// Top left corners of the faces, left to right, then top to bottom.
+  pub fn faces(&self) -> impl Iterator<Item = Pos2> + '_ {
+    self.faces.iter().map(|face| face.top_left)
+  }
+
+  /// Position and facing after stepping over the edge of the net, for every cell on the edge and
+  /// every facing leading off the net.
+  pub fn wraps(&self) -> &HashMap<(Pos2, Dir2), (Pos2, Dir2)> {
+    &self.wraps
+  }
+
+  /// Position and facing after making one step on the cube.
+  pub fn step(&self, pos: Pos2, dir: Dir2) -> (Pos2, Dir2) {
+    self.wraps.get(&(pos, dir)).copied().unwrap_or((pos + dir, dir))
+  }
+}
@@ -0,0 +1,33 @@
+use crate::*;
+
+pub struct Day01 {
+  /// Calories carried by each elf, sorted from the highest.
+  all: Vec<u32>,
+}
+
+impl Solution for Day01 {
+  fn parse(input: &str) -> Result<Self, ParseError> {
+    let lines = parse_lines(input, |line| {
+      if line.is_empty() {
+        Ok(None)
+      } else {
+        parse_value::<u32>(line).map(Some)
+      }
+    })?;
+    let mut all = lines
+      .split(|line| line.is_none())
+      .map(|chunk| chunk.iter().flatten().sum())
+      .collect::<Vec<u32>>();
+    all.sort();
+    all.reverse();
+    Ok(Day01 { all })
+  }
+
+  fn part1(&self) -> String {
+    self.all[0].to_string()
+  }
+
+  fn part2(&self) -> String {
+    (self.all[0] + self.all[1] + self.all[2]).to_string()
+  }
+}
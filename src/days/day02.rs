@@ -0,0 +1,47 @@
+use crate::*;
+
+pub struct Day02 {
+  /// Pairs of (opponent, second column), both mapped to `0..3`.
+  rounds: Vec<(u32, u32)>,
+}
+
+impl Solution for Day02 {
+  fn parse(input: &str) -> Result<Self, ParseError> {
+    let rounds = parse_lines(input, |line| match line.as_bytes() {
+      [first @ b'A'..=b'C', b' ', second @ b'X'..=b'Z'] => Ok((u32::from(first - b'A'), u32::from(second - b'X'))),
+      _ => Err(ParseError::new("expected 'A|B|C X|Y|Z'", line)),
+    })?;
+    Ok(Day02 { rounds })
+  }
+
+  fn part1(&self) -> String {
+    let total: u32 = self
+      .rounds
+      .iter()
+      .map(|&(first, second)| {
+        if first == second {
+          second + 4
+        } else if ((first + 1) % 3) == second {
+          second + 7
+        } else {
+          second + 1
+        }
+      })
+      .sum();
+    total.to_string()
+  }
+
+  fn part2(&self) -> String {
+    let total: u32 = self
+      .rounds
+      .iter()
+      .map(|&(first, second)| match second {
+        0 => ((first + 2) % 3) + 1,
+        1 => first + 4,
+        2 => ((first + 1) % 3) + 7,
+        _ => unreachable!(),
+      })
+      .sum();
+    total.to_string()
+  }
+}
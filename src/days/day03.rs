@@ -0,0 +1,65 @@
+use crate::*;
+
+fn idx(ch: u8) -> usize {
+  if ch.is_ascii_lowercase() {
+    usize::from(ch - b'a')
+  } else {
+    usize::from(ch - b'A' + 26)
+  }
+}
+
+pub struct Day03 {
+  lines: Vec<String>,
+}
+
+impl Solution for Day03 {
+  fn parse(input: &str) -> Result<Self, ParseError> {
+    let lines = parse_lines(input, |line| {
+      let line = line.trim();
+      if let Some(bad) = line.split(|ch: char| ch.is_ascii_alphabetic()).find(|s| !s.is_empty()) {
+        Err(ParseError::new("expected only letters", bad))
+      } else if line.len() % 2 != 0 {
+        Err(ParseError::new("expected even number of items", line))
+      } else {
+        Ok(line.to_owned())
+      }
+    })?;
+    Ok(Day03 { lines })
+  }
+
+  fn part1(&self) -> String {
+    let total: usize = self
+      .lines
+      .iter()
+      .map(|line| {
+        let mut flags = [0; 52];
+        let line = line.as_bytes();
+        for item in line[..line.len() / 2].iter() {
+          flags[idx(*item)] |= 1;
+        }
+        for item in line[line.len() / 2..].iter() {
+          flags[idx(*item)] |= 2;
+        }
+        flags.iter().position(|item| *item == 3).unwrap() + 1
+      })
+      .sum();
+    total.to_string()
+  }
+
+  fn part2(&self) -> String {
+    let total: usize = self
+      .lines
+      .chunks(3)
+      .map(|chunks| {
+        let mut flags = [0; 52];
+        for (chunk, line) in chunks.iter().enumerate() {
+          for item in line.as_bytes().iter().copied() {
+            flags[idx(item)] |= 1 << chunk;
+          }
+        }
+        flags.iter().position(|item| *item == 7).unwrap() + 1
+      })
+      .sum();
+    total.to_string()
+  }
+}
@@ -0,0 +1,42 @@
+use crate::*;
+
+fn range(s: &str) -> Result<(usize, usize), ParseError> {
+  let (from, to) = s
+    .split_once('-')
+    .ok_or_else(|| ParseError::new("expected range 'N-M'", s))?;
+  Ok((parse_value(from)?, parse_value(to)?))
+}
+
+pub struct Day04 {
+  pairs: Vec<((usize, usize), (usize, usize))>,
+}
+
+impl Solution for Day04 {
+  fn parse(input: &str) -> Result<Self, ParseError> {
+    let pairs = parse_lines(input, |line| {
+      let (first, second) = line
+        .split_once(',')
+        .ok_or_else(|| ParseError::new("expected two ranges", line))?;
+      Ok((range(first)?, range(second)?))
+    })?;
+    Ok(Day04 { pairs })
+  }
+
+  fn part1(&self) -> String {
+    self
+      .pairs
+      .iter()
+      .filter(|((x0, x1), (y0, y1))| (x0 <= y0 && x1 >= y1) || (y0 <= x0 && y1 >= x1))
+      .count()
+      .to_string()
+  }
+
+  fn part2(&self) -> String {
+    self
+      .pairs
+      .iter()
+      .filter(|((x0, x1), (y0, y1))| !(x1 < y0 || y1 < x0))
+      .count()
+      .to_string()
+  }
+}
@@ -0,0 +1,70 @@
+use crate::*;
+
+fn initial_stacks() -> [Vec<u8>; 9] {
+  [
+    b"BPNQHDRT".to_vec(),
+    b"WGBJTV".to_vec(),
+    b"NRHDSVMQ".to_vec(),
+    b"PZNMC".to_vec(),
+    b"DZB".to_vec(),
+    b"VCWZ".to_vec(),
+    b"GZNCVQLS".to_vec(),
+    b"LGJMDNV".to_vec(),
+    b"TPMFZCG".to_vec(),
+  ]
+}
+
+fn tops(stacks: &[Vec<u8>]) -> String {
+  stacks.iter().map(|stack| *stack.last().unwrap() as char).collect()
+}
+
+struct Move {
+  count: usize,
+  from: usize,
+  to: usize,
+}
+
+pub struct Day05 {
+  moves: Vec<Move>,
+}
+
+impl Solution for Day05 {
+  fn parse(input: &str) -> Result<Self, ParseError> {
+    let moves = parse_lines(input, |line| match line.split(' ').collect::<Vec<_>>()[..] {
+      ["move", count, "from", from, "to", to] => {
+        let stack = |s: &str| match parse_value::<usize>(s)? {
+          idx @ 1..=9 => Ok(idx - 1),
+          _ => Err(ParseError::new("expected stack number from 1 to 9", s)),
+        };
+        Ok(Move {
+          count: parse_value(count)?,
+          from: stack(from)?,
+          to: stack(to)?,
+        })
+      }
+      _ => Err(ParseError::new("expected 'move N from N to N'", line)),
+    })?;
+    Ok(Day05 { moves })
+  }
+
+  fn part1(&self) -> String {
+    let mut stacks = initial_stacks();
+    for &Move { count, from, to } in &self.moves {
+      for _ in 0..count {
+        let x = stacks[from].pop().unwrap();
+        stacks[to].push(x);
+      }
+    }
+    tops(&stacks)
+  }
+
+  fn part2(&self) -> String {
+    let mut stacks = initial_stacks();
+    for &Move { count, from, to } in &self.moves {
+      let idx_from = stacks[from].len() - count;
+      let taken = stacks[from].drain(idx_from..).collect::<Vec<u8>>();
+      stacks[to].extend(taken);
+    }
+    tops(&stacks)
+  }
+}
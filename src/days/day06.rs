@@ -0,0 +1,31 @@
+use crate::*;
+use std::collections::HashSet;
+
+pub struct Day06 {
+  input: Vec<u8>,
+}
+
+impl Day06 {
+  fn find_marker(&self, size: usize) -> usize {
+    (0..self.input.len() - size)
+      .find(|idx| self.input[*idx..*idx + size].iter().collect::<HashSet<_>>().len() == size)
+      .unwrap()
+      + size
+  }
+}
+
+impl Solution for Day06 {
+  fn parse(input: &str) -> Result<Self, ParseError> {
+    Ok(Day06 {
+      input: input.trim().as_bytes().to_vec(),
+    })
+  }
+
+  fn part1(&self) -> String {
+    self.find_marker(4).to_string()
+  }
+
+  fn part2(&self) -> String {
+    self.find_marker(14).to_string()
+  }
+}
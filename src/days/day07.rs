@@ -0,0 +1,86 @@
+use crate::*;
+use std::collections::HashMap;
+
+pub struct Day07 {
+  /// Total size of every directory, keyed by its path (with a trailing slash).
+  sizes: HashMap<String, i32>,
+}
+
+impl Solution for Day07 {
+  fn parse(input: &str) -> Result<Self, ParseError> {
+    let mut files: HashMap<String, i32> = HashMap::new();
+    let mut prefix = "/".to_owned();
+    files.insert("/".to_owned(), 0);
+
+    let mut it = input.lines().enumerate().peekable();
+    while let Some((idx, command)) = it.next() {
+      match command {
+        "$ cd .." => {
+          let pos = prefix[0..prefix.len() - 1]
+            .rfind('/')
+            .ok_or_else(|| ParseError::new("cannot leave the root directory", command).at_line(idx + 1, command))?;
+          prefix = prefix[..pos + 1].to_owned();
+        }
+        "$ cd /" => {
+          prefix = "/".to_owned();
+        }
+        _ if command.starts_with("$ cd ") => {
+          prefix += &command[5..];
+          prefix += "/"
+        }
+        "$ ls" => {
+          while let Some((idx, entry)) = it.next_if(|(_, s)| !s.starts_with('$')) {
+            if let Some(dir) = entry.strip_prefix("dir ") {
+              let name = prefix.to_owned() + dir + "/";
+              files.insert(name, 0);
+            } else {
+              let (size, name) = entry
+                .split_once(' ')
+                .ok_or_else(|| ParseError::new("expected 'SIZE NAME'", entry))
+                .and_then(|(size, name)| Ok((parse_value::<i32>(size)?, name)))
+                .map_err(|err| err.at_line(idx + 1, entry))?;
+              files.insert(prefix.to_owned() + name, size);
+            }
+          }
+        }
+        _ => return Err(ParseError::new("unknown command", command).at_line(idx + 1, command)),
+      }
+    }
+    let mut sizes = HashMap::new();
+    for dir in files.keys() {
+      if !dir.ends_with('/') {
+        continue;
+      }
+
+      let mut total = 0;
+      for (file, size) in &files {
+        if file.starts_with(dir) {
+          total += size;
+        }
+      }
+      sizes.insert(dir.clone(), total);
+    }
+    Ok(Day07 { sizes })
+  }
+
+  fn part1(&self) -> String {
+    let mut tot = 0;
+    for (name, size) in &self.sizes {
+      if name != "/" && *size <= 100000 {
+        tot += size;
+      }
+    }
+    tot.to_string()
+  }
+
+  fn part2(&self) -> String {
+    let mut min_size = self.sizes["/"];
+    let need = 30000000 - (70000000 - self.sizes["/"]);
+    for size in self.sizes.values() {
+      if *size >= need && *size < min_size {
+        min_size = *size;
+      }
+    }
+    min_size.to_string()
+  }
+}
@@ -0,0 +1,39 @@
+use crate::*;
+
+pub struct Day08 {
+  grid: Grid<i32>,
+}
+
+impl Solution for Day08 {
+  fn parse(input: &str) -> Result<Self, ParseError> {
+    let grid = parse_lines(input, |line| {
+      line
+        .char_indices()
+        .map(|(col, ch)| {
+          let span = col..col + ch.len_utf8();
+          ch.to_digit(10)
+            .map(|digit| digit as i32)
+            .ok_or_else(|| ParseError::new("expected tree height digit", &line[span.clone()]).with_span(span))
+        })
+        .collect::<Result<Vec<_>, _>>()
+    })?;
+    Ok(Day08 {
+      grid: Grid::from_rows(grid),
+    })
+  }
+
+  fn part1(&self) -> String {
+    let visibility = self.grid.visibility(|tree, other| other >= tree);
+    let visible = self.grid.every_pos().filter(|pos| visibility.is_visible(*pos));
+    visible.count().to_string()
+  }
+
+  fn part2(&self) -> String {
+    let visibility = self.grid.visibility(|tree, other| other >= tree);
+    let scores = self
+      .grid
+      .every_pos()
+      .map(|pos| visibility.views[pos].iter().product::<usize>());
+    scores.max().unwrap_or(0).to_string()
+  }
+}
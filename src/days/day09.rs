@@ -0,0 +1,68 @@
+use crate::*;
+use std::collections::HashSet;
+
+#[derive(Default, Clone, Copy, PartialEq, Eq, Hash)]
+struct Pos {
+  x: i32,
+  y: i32,
+}
+
+type Rope = Vec<Pos>;
+
+fn advance(rope: &mut Rope) {
+  for idx in 0..rope.len() - 1 {
+    let dx = rope[idx].x - rope[idx + 1].x;
+    let dy = rope[idx].y - rope[idx + 1].y;
+    if !(-1..=1).contains(&dx) || !(-1..=1).contains(&dy) {
+      rope[idx + 1].x += dx.signum();
+      rope[idx + 1].y += dy.signum();
+    }
+  }
+}
+
+pub struct Day09 {
+  /// Head moves as (dx, dy, count).
+  moves: Vec<(i32, i32, usize)>,
+}
+
+impl Day09 {
+  fn simulate(&self, mut rope: Rope) -> usize {
+    let mut tails = HashSet::new();
+    for &(dx, dy, dist) in &self.moves {
+      for _ in 0..dist {
+        rope[0].x += dx;
+        rope[0].y += dy;
+        advance(&mut rope);
+        tails.insert(*rope.last().unwrap());
+      }
+    }
+    tails.len()
+  }
+}
+
+impl Solution for Day09 {
+  fn parse(input: &str) -> Result<Self, ParseError> {
+    let moves = parse_lines(input, |line| {
+      let (cmd, dist) = line
+        .split_once(' ')
+        .ok_or_else(|| ParseError::new("expected 'DIR COUNT'", line))?;
+      let (dx, dy) = match cmd {
+        "R" => (1, 0),
+        "U" => (0, 1),
+        "L" => (-1, 0),
+        "D" => (0, -1),
+        _ => return Err(ParseError::new("expected direction R, U, L or D", cmd)),
+      };
+      Ok((dx, dy, parse_value::<usize>(dist)?))
+    })?;
+    Ok(Day09 { moves })
+  }
+
+  fn part1(&self) -> String {
+    self.simulate(vec![Pos::default(); 2]).to_string()
+  }
+
+  fn part2(&self) -> String {
+    self.simulate(vec![Pos::default(); 10]).to_string()
+  }
+}
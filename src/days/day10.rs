@@ -0,0 +1,83 @@
+use crate::*;
+use std::collections::HashMap;
+
+struct Machine {
+  cycle: i32,
+  x: i32,
+  screen: Vec<bool>,
+}
+
+impl Machine {
+  fn init() -> Self {
+    Machine {
+      cycle: 0,
+      x: 1,
+      screen: vec![false; 40 * 6],
+    }
+  }
+
+  fn advance(&mut self, instr: i32) {
+    let ray_pos = self.cycle % 40;
+    if (self.x - ray_pos).abs() <= 1 {
+      self.screen[(self.cycle % 240) as usize] = true;
+    }
+    self.x += instr;
+    self.cycle += 1;
+  }
+
+  fn render(&self) -> String {
+    let mut result = String::new();
+    for y in 0..6 {
+      for x in 0..40 {
+        result.push(if self.screen[y * 40 + x] { '#' } else { '.' });
+      }
+      result.push('\n');
+    }
+    result
+  }
+}
+
+pub struct Day10 {
+  /// Register increments for every cycle.
+  processed: Vec<i32>,
+}
+
+impl Solution for Day10 {
+  fn parse(input: &str) -> Result<Self, ParseError> {
+    let instrs = parse_lines(input, |instr| {
+      if instr == "noop" {
+        Ok(None)
+      } else if let Some(arg) = instr.strip_prefix("addx ") {
+        Ok(Some(parse_value::<i32>(arg)?))
+      } else {
+        Err(ParseError::new("unknown instruction", instr))
+      }
+    })?;
+    let mut processed = Vec::new();
+    for instr in instrs {
+      processed.push(0);
+      processed.extend(instr);
+    }
+    Ok(Day10 { processed })
+  }
+
+  fn part1(&self) -> String {
+    let mut machine = Machine::init();
+    let mut state = HashMap::new();
+    for instr in &self.processed {
+      machine.advance(*instr);
+      state.insert(machine.cycle + 1, machine.x);
+    }
+    let cycles = [20, 60, 100, 140, 180, 220];
+    let total: i32 = cycles.iter().map(|c| state[c] * c).sum();
+    total.to_string()
+  }
+
+  fn part2(&self) -> String {
+    let mut machine = Machine::init();
+    for instr in &self.processed {
+      machine.advance(*instr);
+    }
+    machine.render()
+  }
+}
@@ -0,0 +1,123 @@
+use crate::*;
+use pest::iterators::Pairs;
+use pest::Parser;
+use pest_derive::Parser;
+use std::fmt::Debug;
+
+#[derive(Parser)]
+#[grammar = "bin/day11/monkey.pest"]
+struct MonkeyParser;
+
+#[derive(Debug, Clone)]
+struct Monkey {
+  items: Vec<usize>,
+  op: String,
+  lhs: Option<usize>,
+  rhs: Option<usize>,
+  test_div: usize,
+  true_monkey: usize,
+  false_monkey: usize,
+  total: usize,
+}
+
+fn parse_arg(s: &str) -> Result<Option<usize>, ParseError> {
+  if s == "old" {
+    Ok(None)
+  } else {
+    parse_value(s).map(Some)
+  }
+}
+
+impl Monkey {
+  fn from_str(s: &str) -> Result<Monkey, ParseError> {
+    let mut pairs: Pairs<_> = MonkeyParser::parse(Rule::monkey, s)?.next().unwrap().into_inner();
+    Ok(Monkey {
+      items: pairs.parse_list()?,
+      lhs: parse_arg(pairs.next_str()?)?,
+      op: pairs.next_str()?.to_owned(),
+      rhs: parse_arg(pairs.next_str()?)?,
+      test_div: pairs.next_parse()?,
+      true_monkey: pairs.next_parse()?,
+      false_monkey: pairs.next_parse()?,
+      total: 0,
+    })
+  }
+}
+
+fn op(monkey: &Monkey, item: usize) -> usize {
+  let lhs = monkey.lhs.unwrap_or(item);
+  let rhs = monkey.rhs.unwrap_or(item);
+  match monkey.op.as_str() {
+    "*" => lhs * rhs,
+    "+" => lhs + rhs,
+    _ => panic!(),
+  }
+}
+
+fn monkey(monkeys: &mut [Monkey], monkey: usize, worry_drop: bool, worry_div: usize) {
+  let items = std::mem::take(&mut monkeys[monkey].items);
+  monkeys[monkey].total += items.len();
+  for item in items {
+    let mut next = op(&monkeys[monkey], item);
+    if worry_drop {
+      next /= 3;
+    }
+    let nm = if next.is_multiple_of(monkeys[monkey].test_div) {
+      monkeys[monkey].true_monkey
+    } else {
+      monkeys[monkey].false_monkey
+    };
+    next %= worry_div;
+    monkeys[nm].items.push(next);
+  }
+}
+
+fn round(monkeys: &mut [Monkey], worry_drop: bool, worry_div: usize) {
+  for idx in 0..monkeys.len() {
+    monkey(monkeys, idx, worry_drop, worry_div);
+  }
+}
+
+pub struct Day11 {
+  monkeys: Vec<Monkey>,
+}
+
+impl Day11 {
+  fn monkey_business(&self, rounds: usize, worry_drop: bool) -> usize {
+    let mut monkeys = self.monkeys.clone();
+    let worry_div = monkeys.iter().map(|m| m.test_div).product::<usize>();
+    for _ in 0..rounds {
+      round(&mut monkeys, worry_drop, worry_div);
+    }
+    monkeys.sort_by_key(|m| m.total);
+    monkeys[monkeys.len() - 2].total * monkeys[monkeys.len() - 1].total
+  }
+}
+
+impl Solution for Day11 {
+  fn parse(input: &str) -> Result<Self, ParseError> {
+    let mut monkeys = Vec::new();
+    let mut line = 0;
+    for chunk in input.split("\n\n") {
+      monkeys.push(Monkey::from_str(chunk).map_err(|err| err.offset_lines(line))?);
+      line += chunk.lines().count() + 1;
+    }
+    for monkey in &monkeys {
+      if monkey.true_monkey >= monkeys.len() || monkey.false_monkey >= monkeys.len() {
+        return Err(ParseError::new(
+          "throws to unknown monkey",
+          &format!("{} / {}", monkey.true_monkey, monkey.false_monkey),
+        ));
+      }
+    }
+    Ok(Day11 { monkeys })
+  }
+
+  fn part1(&self) -> String {
+    self.monkey_business(20, true).to_string()
+  }
+
+  fn part2(&self) -> String {
+    self.monkey_business(10000, false).to_string()
+  }
+}
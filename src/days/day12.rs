@@ -0,0 +1,87 @@
+use crate::visualize::Channel;
+use crate::*;
+use std::time::Duration;
+
+fn cost_fn(map: &CharMap, from: Pos2, to: Pos2) -> Option<usize> {
+  if map[from] + 1 >= map[to] {
+    Some(1)
+  } else {
+    None
+  }
+}
+
+fn visualize_fn(visualize: &Channel) -> impl Fn(&CharMap, VisitKind, Pos2, usize) + '_ {
+  |map, visit, pos, _cost| {
+    let item = map[pos];
+    let color = match visit {
+      VisitKind::Consider => (0xff, 0, 0),
+      VisitKind::Visit => (0, (255 * u32::from(item - b'a') / 26) as u8, 0),
+    };
+    visualize.draw_map_pixel(pos, color);
+    visualize.sleep(Duration::from_micros(100));
+  }
+}
+
+#[derive(Clone, PartialEq)]
+enum Marker {
+  Start,
+  End,
+}
+
+pub struct Day12 {
+  map: CharMap,
+  start: Pos2,
+  end: Pos2,
+}
+
+impl Day12 {
+  fn path_from_start(&self, visualize: &Channel) -> PathResult {
+    let end = self.end;
+    self
+      .map
+      .find_path_cb(self.start, |_, pos| pos == end, cost_fn, visualize_fn(visualize))
+      .unwrap()
+  }
+}
+
+impl Solution for Day12 {
+  fn parse(input: &str) -> Result<Self, ParseError> {
+    let legend = (b'a'..=b'z')
+      .fold(Legend::new(), |legend, ch| legend.cell(ch, ch))
+      .entity(b'S', Marker::Start, b'a')
+      .entity(b'E', Marker::End, b'z');
+    let parsed = legend.parse(input)?;
+    let start = parsed.single(&Marker::Start, "'S'")?;
+    let end = parsed.single(&Marker::End, "'E'")?;
+    let map = parsed.cells;
+    Ok(Day12 { map, start, end })
+  }
+
+  fn part1(&self) -> String {
+    self.path_from_start(&Channel::empty()).cost.to_string()
+  }
+
+  fn part2(&self) -> String {
+    let map = &self.map;
+    let field = map.distance_field_bfs([self.end], offset_moves(&MOVES_4), |map, from, to| {
+      cost_fn(map, to, from).is_some()
+    });
+    map
+      .every_pos()
+      .filter(|pos| map[*pos] == b'a')
+      .filter_map(|pos| field.distance(pos))
+      .min()
+      .unwrap()
+      .to_string()
+  }
+
+  fn visualize(&self, visualize: &Channel) -> (String, String) {
+    visualize.draw_init(self.map.top_left(), self.map.bottom_right(), |pos| {
+      let item = self.map[pos];
+      (0, (255 * u32::from(item - b'a') / 26) as u8, 0)
+    });
+    let path = self.path_from_start(visualize);
+    visualize.draw_polyline(&path.path(), (0xff, 0xff, 0xff));
+    (path.cost.to_string(), self.part2())
+  }
+}
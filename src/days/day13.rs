@@ -0,0 +1,80 @@
+use crate::*;
+use serde::Deserialize;
+use serde_json::from_str;
+use std::cmp::Ordering;
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(untagged)]
+enum Node {
+  List(Vec<Node>),
+  Value(usize),
+}
+
+impl Ord for Node {
+  fn cmp(&self, other: &Self) -> Ordering {
+    match (self, other) {
+      (Node::Value(l), Node::Value(r)) => l.cmp(r),
+      (Node::List(l), Node::List(r)) => l.cmp(r),
+      (lhs @ Node::Value(_), rhs @ Node::List(_)) => Node::List(vec![lhs.to_owned()]).cmp(rhs),
+      (lhs @ Node::List(_), rhs @ Node::Value(_)) => lhs.cmp(&Node::List(vec![rhs.to_owned()])),
+    }
+  }
+}
+
+impl PartialOrd for Node {
+  fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    Some(self.cmp(other))
+  }
+}
+
+pub struct Day13 {
+  pairs: Vec<(Node, Node)>,
+}
+
+impl Solution for Day13 {
+  fn parse(input: &str) -> Result<Self, ParseError> {
+    let packet = |line: usize, text: &str| {
+      from_str::<Node>(text).map_err(|err| ParseError::new(err.to_string(), text).at_line(line, text))
+    };
+    let pairs = input
+      .split("\n\n")
+      .enumerate()
+      .map(|(idx, item)| {
+        let line = idx * 3 + 1;
+        let (f, s) = item
+          .split_once('\n')
+          .ok_or_else(|| ParseError::new("expected a pair of packets", item).at_line(line, item))?;
+        Ok((packet(line, f)?, packet(line + 1, s.trim_end())?))
+      })
+      .collect::<Result<Vec<_>, ParseError>>()?;
+    Ok(Day13 { pairs })
+  }
+
+  fn part1(&self) -> String {
+    self
+      .pairs
+      .iter()
+      .enumerate()
+      .filter(|(_, (a, b))| a < b)
+      .map(|(idx, _)| idx + 1)
+      .sum::<usize>()
+      .to_string()
+  }
+
+  fn part2(&self) -> String {
+    let mut list = self
+      .pairs
+      .iter()
+      .flat_map(|(f, s)| [f.clone(), s.clone()].into_iter())
+      .collect::<Vec<_>>();
+    let div1 = from_str::<Node>("[[2]]").unwrap();
+    let div2 = from_str::<Node>("[[6]]").unwrap();
+    list.push(div1.clone());
+    list.push(div2.clone());
+    list.sort();
+
+    let div1_idx = list.iter().position(|n| n == &div1).unwrap() + 1;
+    let div2_idx = list.iter().position(|n| n == &div2).unwrap() + 1;
+    (div1_idx * div2_idx).to_string()
+  }
+}
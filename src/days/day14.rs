@@ -0,0 +1,115 @@
+use crate::visualize::{Channel, Color};
+use crate::*;
+
+fn to_pos(pos: &str) -> Result<Pos2, ParseError> {
+  let (x, y) = pos
+    .split_once(',')
+    .ok_or_else(|| ParseError::new("expected 'X,Y'", pos.trim()))?;
+  Ok(Pos2::new(parse_value(x.trim())?, parse_value(y.trim())?))
+}
+
+const DIRS: [Dir2; 3] = [Dir2::new(0, 1), Dir2::new(-1, 1), Dir2::new(1, 1)];
+
+const EMPTY_COLOR: Color = (0, 0, 0);
+const WALL_COLOR: Color = (0xff, 0xff, 0xff);
+const SAND_COLOR: Color = (0xc2, 0xb2, 0x80);
+
+fn drop_sand(map: &mut CharMap, mut pos: Pos2, floor: isize, channel: &Channel) -> bool {
+  while pos.y < floor - 1 {
+    channel.draw_map_pixel(pos, SAND_COLOR);
+    if let Some(d) = DIRS.iter().find(|d| map[pos + *d] == b'.') {
+      channel.draw_map_pixel(pos, EMPTY_COLOR);
+      pos += *d;
+    } else {
+      map[pos] = b'o';
+      return true;
+    }
+  }
+  channel.draw_map_pixel(pos, SAND_COLOR);
+  map[pos] = b'o';
+  false
+}
+
+fn color_fn(map: &CharMap, pos: Pos2) -> Color {
+  match map[pos] {
+    b'.' => EMPTY_COLOR,
+    b'#' => WALL_COLOR,
+    b'o' => SAND_COLOR,
+    _ => unreachable!(),
+  }
+}
+
+pub struct Day14 {
+  map: CharMap,
+  floor_y: isize,
+}
+
+impl Day14 {
+  fn simulate(&self, channel: &Channel) -> (usize, usize) {
+    let mut map = self.map.clone();
+    let floor_y = self.floor_y;
+    channel.draw_init(Pos2::new(500 - floor_y, 0), Pos2::new(500 + floor_y, floor_y), |pos| {
+      color_fn(&map, pos)
+    });
+
+    let mut total = 0;
+    while drop_sand(&mut map, Pos2::new(500, 0), floor_y, channel) {
+      total += 1;
+    }
+    let first = total;
+
+    // Count the last dropped
+    total += 1;
+    while map[Pos2::new(500, 0)] != b'o' {
+      drop_sand(&mut map, Pos2::new(500, 0), floor_y, channel);
+      total += 1;
+    }
+    (first, total)
+  }
+}
+
+impl Solution for Day14 {
+  fn parse(input: &str) -> Result<Self, ParseError> {
+    let lines = parse_lines(input, |line| {
+      line.split("->").map(to_pos).collect::<Result<Vec<_>, _>>()
+    })?;
+    for line in lines.iter().flat_map(|line| line.windows(2)) {
+      if line[0].x != line[1].x && line[0].y != line[1].y {
+        return Err(ParseError::new(
+          "walls must be horizontal or vertical",
+          &format!("{} -> {}", line[0], line[1]),
+        ));
+      }
+    }
+
+    let positions = lines.iter().flat_map(|line| line.iter());
+    let floor_y = positions
+      .clone()
+      .map(|p| p.y)
+      .max()
+      .ok_or_else(|| ParseError::new("no walls", input))?
+      + 2;
+    let mut map = CharMap::empty(BoundsBehavior::grow(b'.'));
+
+    lines
+      .iter()
+      .flat_map(|line| line.as_slice().windows(2))
+      .flat_map(|line| line[0].line_to(line[1]))
+      .for_each(|p| map[p] = b'#');
+    map[Pos2::new(500, 0)] = b'.';
+    Ok(Day14 { map, floor_y })
+  }
+
+  fn part1(&self) -> String {
+    self.simulate(&Channel::empty()).0.to_string()
+  }
+
+  fn part2(&self) -> String {
+    self.simulate(&Channel::empty()).1.to_string()
+  }
+
+  fn visualize(&self, channel: &Channel) -> (String, String) {
+    let (first, second) = self.simulate(channel);
+    (first.to_string(), second.to_string())
+  }
+}
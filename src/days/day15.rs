@@ -0,0 +1,145 @@
+use crate::*;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::collections::HashSet;
+use std::str::FromStr;
+
+static RE: Lazy<Regex> =
+  Lazy::new(|| Regex::new("Sensor at x=(-?\\d+)+, y=(-?\\d+): closest beacon is at x=(-?\\d+), y=(-?\\d+)").unwrap());
+
+#[derive(Debug, Clone, Copy)]
+struct Info {
+  sensor: Pos2,
+  beacon: Pos2,
+}
+
+impl FromStr for Info {
+  type Err = ParseError;
+
+  fn from_str(line: &str) -> Result<Self, Self::Err> {
+    let captures = RE
+      .captures(line)
+      .ok_or_else(|| ParseError::new("expected sensor and beacon positions", line))?;
+    let coord = |idx: usize| parse_value::<isize>(&captures[idx]);
+    Ok(Info {
+      sensor: Pos2::new(coord(1)?, coord(2)?),
+      beacon: Pos2::new(coord(3)?, coord(4)?),
+    })
+  }
+}
+
+impl Info {
+  fn distance(&self) -> isize {
+    // FIXME: manhattan!
+    let Pos2 { x, y } = self.beacon - self.sensor;
+    x.abs() + y.abs()
+  }
+  fn collect_ranges(&self, result: &mut Vec<(isize, isize)>, row: isize, beacon: bool) {
+    let y_dist = (self.sensor.y - row).abs();
+    let x_dist = self.distance() - y_dist;
+    if beacon && self.beacon.y == row {
+      if x_dist == 0 {
+        // The tip
+      } else if self.beacon.x < self.sensor.x {
+        result.push((self.beacon.x + 1, self.sensor.x + x_dist))
+      } else {
+        result.push((self.sensor.x - x_dist, self.beacon.x - 1))
+      }
+    } else if x_dist >= 0 {
+      result.push((self.sensor.x - x_dist, self.sensor.x + x_dist));
+    }
+  }
+
+  fn u_v_allowed(&self) -> [Area; 4] {
+    let uv = self.sensor.to_uv();
+    let d = self.distance();
+    [
+      Area::left_of(uv.x - d - 1),
+      Area::right_of(uv.x + d + 1),
+      Area::top_of(uv.y - d - 1),
+      Area::bottom_of(uv.y + d + 1),
+    ]
+  }
+}
+
+fn count_misses(ranges: &[(isize, isize)]) -> isize {
+  let mut total = 0;
+  let mut it = ranges.iter().copied();
+  let Some((mut from, mut to)) = it.next() else {
+    return 0;
+  };
+  for (nf, nt) in it {
+    if nt <= to {
+      continue;
+    } else if nf <= to {
+      to = nt;
+    } else {
+      total += (to - from) + 1;
+      from = nf;
+      to = nt;
+    }
+  }
+  total += (to - from) + 1;
+  total
+}
+
+fn collect_all(infos: &[Info], row: isize, beacon: bool, ranges: &mut Vec<(isize, isize)>) {
+  ranges.clear();
+  for info in infos {
+    info.collect_ranges(ranges, row, beacon);
+  }
+  ranges.sort_by_key(|(f, _)| *f);
+}
+
+pub struct Day15 {
+  infos: Vec<Info>,
+  /// Row to count the positions without a beacon in.
+  row: isize,
+  /// Size of the square to search for the distress beacon in.
+  range: isize,
+}
+
+impl Solution for Day15 {
+  fn parse(input: &str) -> Result<Self, ParseError> {
+    let infos = parse_lines(input, str::parse::<Info>)?;
+    // The example uses much smaller search parameters than the real input.
+    let small = infos
+      .iter()
+      .all(|info| info.sensor.x.abs() <= 100 && info.sensor.y.abs() <= 100);
+    let (row, range) = if small { (10, 20) } else { (2000000, 4000000) };
+    Ok(Day15 { infos, row, range })
+  }
+
+  fn part1(&self) -> String {
+    let mut ranges = Vec::new();
+    collect_all(&self.infos, self.row, true, &mut ranges);
+    count_misses(&ranges).to_string()
+  }
+
+  fn part2(&self) -> String {
+    let range = self.range;
+    let mut areas = HashSet::new();
+    // UV square that includes our target XY square
+    areas.insert(Area::new(Pos2::new(0, -range), Pos2::new(2 * range, range)));
+    for info in &self.infos {
+      areas = areas
+        .iter()
+        .flat_map(|area| info.u_v_allowed().into_iter().map(|other| area.intersect(&other)))
+        .flatten()
+        .collect::<HashSet<Area>>();
+    }
+    for area in areas {
+      // The solution must be one of the corners in the UV. A better approach would be to intersect
+      // all our UV areas with the target XY area, but haven't figured out simple solution yet.
+      for corner in area.corners() {
+        if (corner.x + corner.y) % 2 == 0 {
+          let candidate = corner.from_uv();
+          if candidate.inside_rect(Pos2::new(0, 0), Pos2::new(range, range)) {
+            return (candidate.x * 4000000 + candidate.y).to_string();
+          }
+        }
+      }
+    }
+    "no gap found".to_owned()
+  }
+}
@@ -1,6 +1,6 @@
-use aoc2022::*;
+use crate::*;
 use once_cell::sync::Lazy;
-use regex::Regex;
+use regex::{Captures, Regex};
 use std::cmp::Ordering;
 use std::collections::{BinaryHeap, HashMap};
 
@@ -42,27 +42,26 @@ impl PartialOrd for VisitState {
   }
 }
 
+fn captures(line: &str) -> Result<Captures<'_>, ParseError> {
+  RE.captures(line)
+    .ok_or_else(|| ParseError::new("expected valve description", line))
+}
+
 impl Valve {
-  fn build_dict<'s>(line: &'s str, dict: &mut HashMap<&'s str, usize>) {
-    let captures = RE.captures(line).unwrap();
-    let mut it = captures.iter().skip(1);
-    let valve = it.next().unwrap().unwrap().as_str().try_into().unwrap();
+  fn build_dict<'s>(line: &'s str, dict: &mut HashMap<&'s str, usize>) -> Result<(), ParseError> {
+    let valve = captures(line)?.get(1).unwrap().as_str();
     let idx = dict.len();
     dict.insert(valve, idx);
+    Ok(())
   }
-  fn from_str(line: &str, dict: &HashMap<&str, usize>) -> Valve {
-    let captures = RE.captures(line).unwrap();
-    let mut it = captures.iter().skip(2);
-    let rate = it.next().unwrap().unwrap().as_str().parse::<isize>().unwrap();
-    let tunnels = it
-      .next()
-      .unwrap()
-      .unwrap()
-      .as_str()
+  fn from_str(line: &str, dict: &HashMap<&str, usize>) -> Result<Valve, ParseError> {
+    let captures = captures(line)?;
+    let rate = parse_value::<isize>(&captures[2])?;
+    let tunnels = captures[3]
       .split(", ")
-      .map(|t| dict[t])
-      .collect::<Vec<_>>();
-    Valve { rate, tunnels }
+      .map(|t| dict.get(t).copied().ok_or_else(|| ParseError::new("unknown valve", t)))
+      .collect::<Result<Vec<_>, _>>()?;
+    Ok(Valve { rate, tunnels })
   }
 }
 
@@ -73,12 +72,7 @@ fn rates(infos: &[Valve], open: usize) -> isize {
     .sum::<isize>()
 }
 
-fn find_path(
-  infos: &[Valve],
-  dists: &Vec<Vec<Option<isize>>>,
-  init: VisitState,
-  finish: isize,
-) -> HashMap<usize, isize> {
+fn find_path(infos: &[Valve], dists: &[Vec<Option<isize>>], init: VisitState, finish: isize) -> HashMap<usize, isize> {
   // We never open the initial state.
   assert_eq!(infos[init.pos].rate, 0);
 
@@ -114,35 +108,7 @@ fn find_path(
   max
 }
 
-fn solve(path: &str) -> (isize, isize) {
-  let input = input_data(16, path);
-  let mut dict = HashMap::new();
-  input.lines().for_each(|line| Valve::build_dict(line, &mut dict));
-  let infos = input
-    .lines()
-    .map(|line| Valve::from_str(line, &dict))
-    .collect::<Vec<_>>();
-
-  let dists = calculate_shortest_dists(&infos);
-
-  let all = find_path(&infos, &dists, VisitState::new(dict["AA"]), 30);
-  let first = *all.iter().map(|(_, score)| score).max().unwrap();
-
-  let myself_scores = find_path(&infos, &dists, VisitState::new(dict["AA"]), 26);
-  let mut second = 0;
-  for (open, myself_score) in myself_scores {
-    let second_init = VisitState {
-      open,
-      ..VisitState::new(dict["AA"])
-    };
-    let elephant_scores = find_path(&infos, &dists, second_init, 26);
-    let elephant_score = *elephant_scores.iter().map(|(_, s)| s).max().unwrap();
-    second = second.max(elephant_score + myself_score - 26 * rates(&infos, open));
-  }
-  (first, second)
-}
-
-fn calculate_shortest_dists(infos: &Vec<Valve>) -> Vec<Vec<Option<isize>>> {
+fn calculate_shortest_dists(infos: &[Valve]) -> Vec<Vec<Option<isize>>> {
   let mut dists = vec![vec![None; infos.len()]; infos.len()];
 
   for (from, info) in infos.iter().enumerate() {
@@ -156,7 +122,7 @@ fn calculate_shortest_dists(infos: &Vec<Valve>) -> Vec<Vec<Option<isize>>> {
     for i in 0..infos.len() {
       for j in 0..infos.len() {
         match (dists[i][k], dists[k][j]) {
-          (Some(ik), Some(kj)) if dists[i][j].map_or(true, |ij| ij > ik + kj) => {
+          (Some(ik), Some(kj)) if dists[i][j].is_none_or(|ij| ij > ik + kj) => {
             dists[i][j] = Some(ik + kj);
           }
           _ => {}
@@ -167,16 +133,50 @@ fn calculate_shortest_dists(infos: &Vec<Valve>) -> Vec<Vec<Option<isize>>> {
   dists
 }
 
-#[test]
-fn test() {
-  assert_eq!((1651, 1707), solve("test.txt"));
-  assert_eq!((1638, 2400), solve("input.txt"));
+pub struct Day16 {
+  infos: Vec<Valve>,
+  dists: Vec<Vec<Option<isize>>>,
+  start: usize,
 }
 
-fn main() {
-  let test = solve("test.txt");
-  println!("test.txt: {} and {}", test.0, test.1);
+impl Solution for Day16 {
+  fn parse(input: &str) -> Result<Self, ParseError> {
+    let mut dict = HashMap::new();
+    parse_lines(input, |line| Valve::build_dict(line, &mut dict))?;
+    // Open valves are tracked as a bit mask.
+    if dict.len() > usize::BITS as usize {
+      return Err(ParseError::new(
+        format!("at most {} valves are supported", usize::BITS),
+        "",
+      ));
+    }
+    let start = *dict
+      .get("AA")
+      .ok_or_else(|| ParseError::new("no starting valve 'AA'", ""))?;
+    let infos = parse_lines(input, |line| Valve::from_str(line, &dict))?;
 
-  let input = solve("input.txt");
-  println!("input.txt: {} and {}", input.0, input.1);
+    let dists = calculate_shortest_dists(&infos);
+    Ok(Day16 { infos, dists, start })
+  }
+
+  fn part1(&self) -> String {
+    let all = find_path(&self.infos, &self.dists, VisitState::new(self.start), 30);
+    all.values().max().unwrap().to_string()
+  }
+
+  fn part2(&self) -> String {
+    let infos = &self.infos;
+    let myself_scores = find_path(infos, &self.dists, VisitState::new(self.start), 26);
+    let mut second = 0;
+    for (open, myself_score) in myself_scores {
+      let second_init = VisitState {
+        open,
+        ..VisitState::new(self.start)
+      };
+      let elephant_scores = find_path(infos, &self.dists, second_init, 26);
+      let elephant_score = *elephant_scores.values().max().unwrap();
+      second = second.max(elephant_score + myself_score - 26 * rates(infos, open));
+    }
+    second.to_string()
+  }
 }
@@ -1,4 +1,4 @@
-use aoc2022::*;
+use crate::*;
 use std::collections::HashMap;
 
 #[derive(Clone, Copy)]
@@ -19,10 +19,10 @@ fn has_overlap(state: &[u8], pat: usize, pos: Pos2) -> bool {
 
 fn cache_key(state: &[u8], jet_idx: usize, pat_idx: usize) -> Option<RepeatKey> {
   let mut heights = [0usize; 7];
-  for idx in 0..7 {
+  for (idx, height) in heights.iter_mut().enumerate() {
     let mask = 1 << (7 - idx);
-    heights[idx] = state.iter().rev().take_while(|line| (*line & mask) == 0).count();
-    if heights[idx] == state.len() {
+    *height = state.iter().rev().take_while(|line| (*line & mask) == 0).count();
+    if *height == state.len() {
       return None;
     }
   }
@@ -35,7 +35,7 @@ fn cache_key(state: &[u8], jet_idx: usize, pat_idx: usize) -> Option<RepeatKey>
 
 const DOWN: Dir2 = Dir2::new(0, -1);
 
-fn step(state: &mut Vec<u8>, dir: u8, pat: usize, mut pos: Pos2) -> Pos2 {
+fn step(state: &mut [u8], dir: u8, pat: usize, mut pos: Pos2) -> Pos2 {
   let x = pos.x as usize;
   let (mask, offset) = match dir {
     b'<' => (0b10000000, Dir2::new(-1, 0)),
@@ -134,25 +134,27 @@ fn solve_for(jets: &[u8], target: usize) -> isize {
   height + highest(&state)
 }
 
-fn solve(path: &str) -> (isize, isize) {
-  let jets = input_data(17, path);
-  let jets = jets.trim().as_bytes();
-
-  let first = solve_for(jets, 2022);
-  let second = solve_for(jets, 1000000000000);
-  (first, second)
+pub struct Day17 {
+  jets: Vec<u8>,
 }
 
-#[test]
-fn test() {
-  assert_eq!((3068, 1514285714288), solve("test.txt"));
-  assert_eq!((3227, 1597714285698), solve("input.txt"));
-}
+impl Solution for Day17 {
+  fn parse(input: &str) -> Result<Self, ParseError> {
+    let jets = input.trim();
+    if let Some(col) = jets.find(|ch| ch != '<' && ch != '>') {
+      let len = jets[col..].chars().next().unwrap().len_utf8();
+      return Err(ParseError::new("expected '<' or '>'", &jets[col..col + len]).with_span(col..col + len));
+    }
+    Ok(Day17 {
+      jets: jets.as_bytes().to_vec(),
+    })
+  }
 
-fn main() {
-  let test = solve("test.txt");
-  println!("test.txt: {} and {}", test.0, test.1);
+  fn part1(&self) -> String {
+    solve_for(&self.jets, 2022).to_string()
+  }
 
-  let input = solve("input.txt");
-  println!("input.txt: {} and {}", input.0, input.1);
+  fn part2(&self) -> String {
+    solve_for(&self.jets, 1000000000000).to_string()
+  }
 }
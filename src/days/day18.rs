@@ -0,0 +1,86 @@
+use crate::*;
+
+pub struct Day18 {
+  coords: Vec<Pos3>,
+  low: Pos3,
+  high: Pos3,
+  dims: Pos3,
+  /// Cubes are marked as 1, the outside air reachable from the corner is marked as 2.
+  map: Vec<u8>,
+}
+
+impl Day18 {
+  fn count_faces(&self, face_fn: impl Fn(u8) -> bool) -> usize {
+    let Day18 {
+      low, high, dims, map, ..
+    } = self;
+    self
+      .coords
+      .iter()
+      .map(|pos| {
+        Dir3::all_6()
+          .filter(|d| {
+            let next = *pos + *d;
+            !next.inside_rect(*low, *high) || {
+              let next = next - *low;
+              face_fn(map[(next.z * dims.x * dims.y + next.y * dims.x + next.x) as usize])
+            }
+          })
+          .count()
+      })
+      .sum::<usize>()
+  }
+}
+
+impl Solution for Day18 {
+  fn parse(input: &str) -> Result<Self, ParseError> {
+    let coords = parse_lines(input, str::parse::<Pos3>)?;
+    if let Some(pos) = coords.iter().find(|c| c.x < 0 || c.y < 0 || c.z < 0) {
+      return Err(ParseError::new("coordinates must not be negative", &pos.to_string()));
+    }
+
+    let low = Pos3::new(-1, -1, -1);
+    let high = Pos3 {
+      x: coords.iter().map(|c| c.x).max().unwrap_or(0) + 1,
+      y: coords.iter().map(|c| c.y).max().unwrap_or(0) + 1,
+      z: coords.iter().map(|c| c.z).max().unwrap_or(0) + 1,
+    };
+    let dims = high - low + Dir3::new(1, 1, 1);
+    let mut map = vec![0u8; (dims.x * dims.y * dims.z) as usize];
+    for pos in &coords {
+      let pos2 = *pos - low;
+      map[(pos2.z * dims.x * dims.y + pos2.y * dims.x + pos2.x) as usize] = 1;
+    }
+
+    let mut queue = vec![low];
+    while let Some(pos) = queue.pop() {
+      let pos2 = pos - low;
+      map[(pos2.z * dims.x * dims.y + pos2.y * dims.x + pos2.x) as usize] = 2;
+      queue.extend(
+        Dir3::all_6()
+          .map(|d| pos + d)
+          .filter(|next| next.inside_rect(low, high))
+          .filter(|next| {
+            let next = *next - low;
+            map[(next.z * dims.x * dims.y + next.y * dims.x + next.x) as usize] == 0
+          }),
+      );
+    }
+
+    Ok(Day18 {
+      coords,
+      low,
+      high,
+      dims,
+      map,
+    })
+  }
+
+  fn part1(&self) -> String {
+    self.count_faces(|cell| cell != 1).to_string()
+  }
+
+  fn part2(&self) -> String {
+    self.count_faces(|cell| cell == 2).to_string()
+  }
+}
@@ -1,4 +1,4 @@
-use aoc2022::*;
+use crate::*;
 use once_cell::sync::Lazy;
 use regex::{Match, Regex};
 use std::collections::HashMap;
@@ -65,24 +65,26 @@ struct Blueprint {
   geode_obsidian: usize,
 }
 
-fn num<'a>(it: &mut impl Iterator<Item = Option<Match<'a>>>) -> usize {
-  it.next().unwrap().unwrap().as_str().parse().unwrap()
+fn num<'a>(it: &mut impl Iterator<Item = Option<Match<'a>>>) -> Result<usize, ParseError> {
+  parse_value(it.next().unwrap().unwrap().as_str())
 }
 
 impl FromStr for Blueprint {
-  type Err = ();
+  type Err = ParseError;
 
   fn from_str(line: &str) -> Result<Self, Self::Err> {
-    let captures = RE.captures(line).unwrap();
+    let captures = RE
+      .captures(line)
+      .ok_or_else(|| ParseError::new("expected blueprint description", line))?;
     let mut it = captures.iter().skip(1);
     Ok(Blueprint {
-      blueprint: num(&mut it),
-      ore_ore: num(&mut it),
-      clay_ore: num(&mut it),
-      obsidian_ore: num(&mut it),
-      obsidian_clay: num(&mut it),
-      geode_ore: num(&mut it),
-      geode_obsidian: num(&mut it),
+      blueprint: num(&mut it)?,
+      ore_ore: num(&mut it)?,
+      clay_ore: num(&mut it)?,
+      obsidian_ore: num(&mut it)?,
+      obsidian_clay: num(&mut it)?,
+      geode_ore: num(&mut it)?,
+      geode_obsidian: num(&mut it)?,
     })
   }
 }
@@ -93,8 +95,7 @@ fn when(resource: usize, prod: usize, req: usize) -> Option<usize> {
   } else if prod == 0 {
     None
   } else {
-    let need = (req - resource + prod - 1) / prod;
-    Some(need)
+    Some((req - resource).div_ceil(prod))
   }
 }
 
@@ -193,34 +194,29 @@ fn blueprint(blueprint: &Blueprint, limit: usize) -> usize {
   max
 }
 
-fn solve(path: &str) -> (usize, usize) {
-  let blueprints = input_data(19, path)
-    .lines()
-    .map(|x| x.parse::<Blueprint>().unwrap())
-    .collect::<Vec<_>>();
+pub struct Day19 {
+  blueprints: Vec<Blueprint>,
+}
 
-  let mut first = 0;
-  for b in &blueprints {
-    first += blueprint(b, 24) * b.blueprint;
+impl Solution for Day19 {
+  fn parse(input: &str) -> Result<Self, ParseError> {
+    let blueprints = parse_lines(input, str::parse::<Blueprint>)?;
+    Ok(Day19 { blueprints })
   }
 
-  let mut second = 1;
-  for b in &blueprints[0..blueprints.len().min(3)] {
-    second *= blueprint(b, 32);
+  fn part1(&self) -> String {
+    let mut first = 0;
+    for b in &self.blueprints {
+      first += blueprint(b, 24) * b.blueprint;
+    }
+    first.to_string()
   }
-  (first, second)
-}
-
-#[test]
-fn test() {
-  assert_eq!((33, 3472), solve("test.txt"));
-  assert_eq!((960, 2040), solve("input.txt"));
-}
-
-fn main() {
-  let test = solve("test.txt");
-  println!("test.txt: {} and {}", test.0, test.1);
 
-  let input = solve("input.txt");
-  println!("input.txt: {} and {}", input.0, input.1);
+  fn part2(&self) -> String {
+    let mut second = 1;
+    for b in &self.blueprints[0..self.blueprints.len().min(3)] {
+      second *= blueprint(b, 32);
+    }
+    second.to_string()
+  }
 }
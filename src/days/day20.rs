@@ -1,4 +1,4 @@
-use aoc2022::*;
+use crate::*;
 use std::cell::RefCell;
 use std::rc::Rc;
 
@@ -49,11 +49,10 @@ fn skip(mut it: Rc<Node>, count: isize) -> Rc<Node> {
   it
 }
 
-fn solve_inner(path: &str, mul: isize, iter: usize) -> isize {
-  let first_list = input_data(20, path)
-    .lines()
-    .map(|line| line.parse::<isize>().unwrap())
-    .map(|value| {
+fn solve_inner(values: &[isize], mul: isize, iter: usize) -> isize {
+  let first_list = values
+    .iter()
+    .map(|&value| {
       Rc::new(Node {
         value,
         prev: RefCell::new(None),
@@ -79,22 +78,24 @@ fn solve_inner(path: &str, mul: isize, iter: usize) -> isize {
   sum * mul
 }
 
-fn solve(path: &str) -> (isize, isize) {
-  let first = solve_inner(path, 1, 1);
-  let second = solve_inner(path, 811589153, 10);
-  (first, second)
+pub struct Day20 {
+  values: Vec<isize>,
 }
 
-#[test]
-fn test() {
-  assert_eq!((3, 1623178306), solve("test.txt"));
-  assert_eq!((8764, 535648840980), solve("input.txt"));
-}
+impl Solution for Day20 {
+  fn parse(input: &str) -> Result<Self, ParseError> {
+    let values = parse_lines(input, parse_value::<isize>)?;
+    if values.iter().filter(|v| **v == 0).count() != 1 {
+      return Err(ParseError::new("expected exactly one zero", ""));
+    }
+    Ok(Day20 { values })
+  }
 
-fn main() {
-  let test = solve("test.txt");
-  println!("test.txt: {} and {}", test.0, test.1);
+  fn part1(&self) -> String {
+    solve_inner(&self.values, 1, 1).to_string()
+  }
 
-  let input = solve("input.txt");
-  println!("input.txt: {} and {}", input.0, input.1);
+  fn part2(&self) -> String {
+    solve_inner(&self.values, 811589153, 10).to_string()
+  }
 }
@@ -1,4 +1,4 @@
-use aoc2022::*;
+use crate::*;
 use pest::iterators::Pairs;
 use pest::Parser;
 use pest_derive::Parser;
@@ -9,45 +9,44 @@ use std::ops::{Add, Div, Mul, Sub};
 #[grammar = "bin/day21/formula.pest"]
 struct FormulaParser;
 
-#[derive(Debug, Clone, Copy)]
-enum Formula<'a> {
+#[derive(Debug, Clone)]
+enum Formula {
   Const(isize),
-  Eval { left: &'a str, op: char, right: &'a str },
-}
-
-fn parse(s: &str) -> (String, Formula) {
-  let mut pairs: Pairs<_> = FormulaParser::parse(Rule::formula, s)
-    .unwrap()
-    .next()
-    .unwrap()
-    .into_inner();
-  let name = pairs.next_str().to_owned();
-  let formula = match pairs.peek().unwrap().as_rule() {
-    Rule::val => Formula::Const(pairs.next_parse()),
+  Eval { left: String, op: char, right: String },
+}
+
+fn parse(s: &str) -> Result<(String, Formula), ParseError> {
+  let mut pairs: Pairs<_> = FormulaParser::parse(Rule::formula, s)?.next().unwrap().into_inner();
+  let name = pairs.next_str()?.to_owned();
+  let formula = match pairs.peek().map(|pair| pair.as_rule()) {
+    Some(Rule::val) => Formula::Const(pairs.next_parse()?),
     _ => {
-      let left = pairs.next().unwrap().as_str();
-      let op = pairs.next_str().chars().next().unwrap();
-      let right = pairs.next().unwrap().as_str();
+      let left = pairs.next_str()?.to_owned();
+      let op = pairs.next_str()?.chars().next().unwrap();
+      let right = pairs.next_str()?.to_owned();
       Formula::Eval { left, op, right }
     }
   };
-  (name, formula)
+  Ok((name, formula))
 }
 
 fn eval(map: &HashMap<String, Formula>, key: &str) -> isize {
   match map[key] {
     Formula::Const(result) => result,
-    Formula::Eval { left, op, right } => {
+    Formula::Eval {
+      ref left,
+      op,
+      ref right,
+    } => {
       let left = eval(map, left);
       let right = eval(map, right);
-      let result = match op {
+      match op {
         '+' => left + right,
         '-' => left - right,
         '*' => left * right,
         '/' => left / right,
         _ => unreachable!(),
-      };
-      result
+      }
     }
   }
 }
@@ -151,50 +150,61 @@ fn eval2(map: &HashMap<String, Formula>, key: &str) -> Val {
   match map[key] {
     _ if key == "humn" => Val(Num(0, 1), 1),
     Formula::Const(result) => Val(Num(result, 0), 1),
-    Formula::Eval { left, op, right } => {
+    Formula::Eval {
+      ref left,
+      op,
+      ref right,
+    } => {
       let left = eval2(map, left);
       let right = eval2(map, right);
-      let result = match op {
+      match op {
         '+' => left + right,
         '-' => left - right,
         '*' => left * right,
         '/' => left / right,
         _ => unreachable!(),
-      };
-      result
+      }
     }
   }
 }
 
-fn solve(path: &str) -> (isize, isize) {
-  let input = input_data(21, path);
-  let mut input = input.lines().map(parse).collect::<HashMap<String, Formula>>();
-
-  let first = eval(&mut input, "root");
-
-  let second = match input["root"] {
-    Formula::Eval { left, right, .. } => {
-      let left = eval2(&input, left);
-      let right = eval2(&input, right);
-      let res = left - right;
-      -res.0 .0 / res.0 .1
-    }
-    _ => unreachable!(),
-  };
-
-  (first, second)
+pub struct Day21 {
+  formulas: HashMap<String, Formula>,
 }
 
-#[test]
-fn test() {
-  assert_eq!((152, 301), solve("test.txt"));
-  assert_eq!((331319379445180, 3715799488132), solve("input.txt"));
-}
+impl Solution for Day21 {
+  fn parse(input: &str) -> Result<Self, ParseError> {
+    let formulas = parse_lines(input, parse)?
+      .into_iter()
+      .collect::<HashMap<String, Formula>>();
+    for formula in formulas.values() {
+      if let Formula::Eval { left, right, .. } = formula {
+        if let Some(name) = [left, right].into_iter().find(|name| !formulas.contains_key(*name)) {
+          return Err(ParseError::new("unknown monkey", name));
+        }
+      }
+    }
+    match formulas.get("root") {
+      Some(Formula::Eval { .. }) => Ok(Day21 { formulas }),
+      _ => Err(ParseError::new("'root' must be an operation", "root")),
+    }
+  }
 
-fn main() {
-  let test = solve("test.txt");
-  println!("test.txt: {} and {}", test.0, test.1);
+  fn part1(&self) -> String {
+    eval(&self.formulas, "root").to_string()
+  }
 
-  let input = solve("input.txt");
-  println!("input.txt: {} and {}", input.0, input.1);
+  fn part2(&self) -> String {
+    match self.formulas["root"] {
+      Formula::Eval {
+        ref left, ref right, ..
+      } => {
+        let left = eval2(&self.formulas, left);
+        let right = eval2(&self.formulas, right);
+        let res = left - right;
+        (-res.0 .0 / res.0 .1).to_string()
+      }
+      _ => unreachable!(),
+    }
+  }
 }
@@ -0,0 +1,131 @@
+use crate::*;
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+
+#[derive(Clone, Copy, IntoPrimitive, TryFromPrimitive, PartialEq, Eq, Hash, Debug)]
+#[repr(usize)]
+enum Dir {
+  Right = 0,
+  Down = 1,
+  Left = 2,
+  Up = 3,
+}
+
+impl Dir {
+  fn right(self) -> Dir {
+    (((self as usize) + 1) % 4).try_into().unwrap()
+  }
+
+  fn left(self) -> Dir {
+    self.right().right().right()
+  }
+}
+
+const DIRS: [Dir2; 4] = [Dir2::new(1, 0), Dir2::new(0, 1), Dir2::new(-1, 0), Dir2::new(0, -1)];
+
+#[derive(Clone, Copy, Debug)]
+enum Cmd {
+  Right,
+  Left,
+  Walk(usize),
+}
+
+fn walk(
+  map: &CharMap,
+  mut pos: Pos2,
+  mut dir: Dir,
+  steps: usize,
+  step_fn: &impl Fn(Pos2, Dir) -> (Pos2, Dir),
+) -> (Pos2, Dir) {
+  for _ in 0..steps {
+    let (next_pos, next_dir) = step_fn(pos, dir);
+    if map[next_pos] == b'#' {
+      break;
+    }
+    pos = next_pos;
+    dir = next_dir;
+  }
+  (pos, dir)
+}
+
+fn walk_map(map: &CharMap, cmds: &[Cmd], step_fn: impl Fn(Pos2, Dir) -> (Pos2, Dir)) -> (Pos2, Dir) {
+  let x = (0..).find(|x| map[Pos2::new(*x, 0)] == b'.').unwrap();
+  let mut pos = Pos2::new(x, 0);
+  let mut dir = Dir::Right;
+  for cmd in cmds {
+    match *cmd {
+      Cmd::Right => dir = dir.right(),
+      Cmd::Left => dir = dir.left(),
+      Cmd::Walk(steps) => (pos, dir) = walk(map, pos, dir, steps, &step_fn),
+    }
+  }
+  (pos, dir)
+}
+
+fn score(pos: Pos2, dir: Dir) -> isize {
+  1000 * (pos.y + 1) + 4 * (pos.x + 1) + (dir as isize)
+}
+
+pub struct Day22 {
+  map: CharMap,
+  cube: CubeNet,
+  cmds: Vec<Cmd>,
+}
+
+impl Solution for Day22 {
+  fn parse(input: &str) -> Result<Self, ParseError> {
+    let (map, cmds) = input
+      .split_once("\n\n")
+      .ok_or_else(|| ParseError::new("expected map and path separated by an empty line", ""))?;
+    let cmds = cmds.trim();
+    let line = map.lines().count() + 2;
+    if let Some(col) = cmds.find(|ch: char| ch != 'L' && ch != 'R' && !ch.is_ascii_digit()) {
+      let len = cmds[col..].chars().next().unwrap().len_utf8();
+      return Err(ParseError::new("expected 'L', 'R' or steps count", &cmds[col..col + len]).at_line(line, cmds));
+    }
+    let mut parsed = Vec::new();
+    let mut col = 0;
+    while col < cmds.len() {
+      let len = cmds[col..]
+        .find(|ch: char| !ch.is_ascii_digit())
+        .unwrap_or(cmds.len() - col)
+        .max(1);
+      let cmd = match &cmds[col..col + len] {
+        "R" => Cmd::Right,
+        "L" => Cmd::Left,
+        steps => {
+          let steps = steps.parse().map_err(|_| {
+            ParseError::new("steps count is too large", steps)
+              .at_line(line, cmds)
+              .with_span(col..col + len)
+          })?;
+          Cmd::Walk(steps)
+        }
+      };
+      parsed.push(cmd);
+      col += len;
+    }
+    let map = CharMap::from_text(map).with_bounds(BoundsBehavior::wrap_non_blank(b' '));
+    let cube = CubeNet::fold(&map, b' ')?;
+    Ok(Day22 {
+      map,
+      cube,
+      cmds: parsed,
+    })
+  }
+
+  fn part1(&self) -> String {
+    let (pos, dir) = walk_map(&self.map, &self.cmds, |pos, dir| {
+      (self.map.neighbor(pos, DIRS[usize::from(dir)]).unwrap(), dir)
+    });
+    score(pos, dir).to_string()
+  }
+
+  fn part2(&self) -> String {
+    let (pos, dir) = walk_map(&self.map, &self.cmds, |pos, dir| {
+      let (next_pos, next_dir) = self.cube.step(pos, DIRS[usize::from(dir)]);
+      let next_dir = DIRS.iter().position(|dir| *dir == next_dir).unwrap();
+      (next_pos, next_dir.try_into().unwrap())
+    });
+    score(pos, dir).to_string()
+  }
+}
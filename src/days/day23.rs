@@ -0,0 +1,103 @@
+use crate::*;
+
+const DIRS: [[Dir2; 3]; 4] = [
+  [Dir2::new(0, -1), Dir2::new(1, -1), Dir2::new(-1, -1)],
+  [Dir2::new(0, 1), Dir2::new(1, 1), Dir2::new(-1, 1)],
+  [Dir2::new(-1, 0), Dir2::new(-1, -1), Dir2::new(-1, 1)],
+  [Dir2::new(1, 0), Dir2::new(1, -1), Dir2::new(1, 1)],
+];
+
+#[derive(Clone, PartialEq)]
+struct Elf;
+
+#[derive(Clone)]
+struct Elves {
+  positions: Vec<Pos2>,
+  map: SparseGrid<bool>,
+  /// Amount of elves proposing to move to the position in the current round.
+  proposals: SparseGrid<u8>,
+}
+
+impl Elves {
+  /// Run a single round, returns if any elf has moved.
+  fn advance(&mut self, offset: usize) -> bool {
+    let map = &self.map;
+    let targets = self
+      .positions
+      .iter()
+      .map(|elf| {
+        if !Dir2::all_8().any(|dir| map[*elf + dir]) {
+          return None;
+        }
+        (0..4)
+          .map(|idx| &DIRS[(idx + offset) % 4])
+          .find(|dirs| dirs.iter().all(|d| !map[*elf + *d]))
+          .map(|dirs| *elf + dirs[0])
+      })
+      .collect::<Vec<_>>();
+
+    for target in targets.iter().flatten() {
+      self.proposals[*target] += 1;
+    }
+    let mut moved = false;
+    for (elf, target) in self.positions.iter_mut().zip(&targets) {
+      if let Some(target) = *target {
+        if self.proposals[target] == 1 {
+          self.map[*elf] = false;
+          self.map[target] = true;
+          *elf = target;
+          moved = true;
+        }
+      }
+    }
+    for target in targets.iter().flatten() {
+      self.proposals[*target] = 0;
+    }
+    moved
+  }
+}
+
+pub struct Day23 {
+  elves: Elves,
+}
+
+impl Solution for Day23 {
+  fn parse(input: &str) -> Result<Self, ParseError> {
+    let legend = Legend::new().cell(b'.', ()).entity(b'#', Elf, ());
+    let positions = legend
+      .parse(input)?
+      .entities
+      .into_iter()
+      .map(|(pos, Elf)| pos)
+      .collect();
+    let mut elves = Elves {
+      positions,
+      map: SparseGrid::new(false),
+      proposals: SparseGrid::new(0),
+    };
+    for elf in &elves.positions {
+      elves.map[*elf] = true;
+    }
+    Ok(Day23 { elves })
+  }
+
+  fn part1(&self) -> String {
+    let mut elves = self.elves.clone();
+    for idx in 0..10 {
+      elves.advance(idx);
+    }
+    let area = elves.map.bounding_box().unwrap();
+    let dims = area.bottom_right - area.top_left + Dir2::new(1, 1);
+    (dims.x * dims.y - (elves.positions.len() as isize)).to_string()
+  }
+
+  fn part2(&self) -> String {
+    let mut elves = self.elves.clone();
+    for idx in 0.. {
+      if !elves.advance(idx) {
+        return (idx + 1).to_string();
+      }
+    }
+    unreachable!()
+  }
+}
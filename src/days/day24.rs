@@ -0,0 +1,151 @@
+use crate::*;
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashSet};
+
+/// Direction of the wind blowing at every position of the valley at the start.
+type Valley = Grid<Option<Dir2>>;
+
+/// Tells if the destination is free of winds at the given step. Winds blow through the walls to
+/// the other side of the valley, which wraps around.
+fn allowed(valley: &Valley, dest: Pos2, step: usize) -> bool {
+  Dir2::all_4().all(|dir| {
+    let origin = Pos2::new(dest.x - dir.dx * step as isize, dest.y - dir.dy * step as isize);
+    valley[origin] != Some(dir)
+  })
+}
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
+struct PathState {
+  pos: Pos2,
+  step: usize,
+}
+
+impl Ord for PathState {
+  fn cmp(&self, other: &Self) -> Ordering {
+    other.step.cmp(&self.step).then_with(|| self.pos.cmp(&other.pos))
+  }
+}
+
+impl PartialOrd for PathState {
+  fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    Some(self.cmp(other))
+  }
+}
+
+fn gcd(first: usize, second: usize) -> usize {
+  if second == 0 {
+    first
+  } else {
+    gcd(second, first % second)
+  }
+}
+
+/// Earliest step at which the end is reached, `None` if it never is. Winds repeat with a period
+/// of lcm(width, height) steps, so every position is only visited once per step of the period.
+fn bfs(valley: &Valley, start: Pos2, step: usize, end: Pos2) -> Option<usize> {
+  let dims = valley.dims();
+  let (width, height) = (dims.x as usize, dims.y as usize);
+  let period = width / gcd(width, height) * height;
+  let mut visited = HashSet::new();
+  let mut queue = BinaryHeap::new();
+  queue.push(PathState { pos: start, step });
+  while let Some(PathState { pos, mut step }) = queue.pop() {
+    if !visited.insert(PathState {
+      pos,
+      step: step % period,
+    }) {
+      continue;
+    }
+    if pos == end {
+      return Some(step);
+    }
+
+    step += 1;
+
+    if !valley.is_in_bounds(pos) || allowed(valley, pos, step) {
+      queue.push(PathState { pos, step });
+    }
+
+    for dir in Dir2::all_4() {
+      let dest = pos + dir;
+      if valley.is_in_bounds(dest) && allowed(valley, dest, step) {
+        queue.push(PathState { pos: dest, step });
+      }
+    }
+  }
+  None
+}
+
+pub struct Day24 {
+  /// Valley without the walls, wrapping around.
+  valley: Valley,
+}
+
+impl Day24 {
+  fn there(&self) -> Option<usize> {
+    Some(bfs(&self.valley, Pos2::new(0, -1), 0, self.valley.bottom_right())? + 1)
+  }
+
+  fn there_and_back_again(&self) -> Option<usize> {
+    let start = Pos2::new(0, -1);
+    let end = self.valley.bottom_right() + Pos2::new(0, 1);
+    let start_target = Pos2::new(0, 0);
+    let end_target = self.valley.bottom_right();
+    let first = self.there()?;
+    let back = bfs(&self.valley, end, first, start_target)? + 1;
+    Some(bfs(&self.valley, start, back, end_target)? + 1)
+  }
+}
+
+impl Solution for Day24 {
+  fn parse(input: &str) -> Result<Self, ParseError> {
+    // Cells tell if they are open, winds blow over open ground
+    let legend = Legend::new()
+      .cell(b'#', false)
+      .cell(b'.', true)
+      .cell(b'E', true)
+      .entity(b'>', Dir2::new(1, 0), true)
+      .entity(b'v', Dir2::new(0, 1), true)
+      .entity(b'<', Dir2::new(-1, 0), true)
+      .entity(b'^', Dir2::new(0, -1), true);
+    let parsed = legend.parse(input)?;
+    let dims = parsed.cells.dims();
+    if dims.x < 3 || dims.y < 3 {
+      return Err(ParseError::new("no valley inside the walls", input));
+    }
+    if !parsed.cells[Pos2::new(1, 0)] {
+      return Err(ParseError::new(
+        "no entry in the top wall",
+        input.lines().next().unwrap_or(""),
+      ));
+    }
+    if !parsed.cells[dims - Pos2::new(2, 1)] {
+      return Err(ParseError::new(
+        "no exit in the bottom wall",
+        input.lines().last().unwrap_or(""),
+      ));
+    }
+    let mut winds = parsed.cells.map(|_| None);
+    for (pos, dir) in parsed.entities {
+      winds[pos] = Some(dir);
+    }
+    let interior = Area::new(Pos2::new(1, 1), winds.bottom_right() - Pos2::new(1, 1));
+    let valley = winds
+      .crop(interior)
+      .with_offset(Pos2::new(0, 0))
+      .with_bounds(BoundsBehavior::Wrap);
+    Ok(Day24 { valley })
+  }
+
+  fn part1(&self) -> String {
+    self
+      .there()
+      .map_or("no path found".to_owned(), |steps| steps.to_string())
+  }
+
+  fn part2(&self) -> String {
+    self
+      .there_and_back_again()
+      .map_or("no path found".to_owned(), |steps| steps.to_string())
+  }
+}
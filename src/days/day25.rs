@@ -0,0 +1,64 @@
+use crate::*;
+
+fn from_snafu(s: &str) -> Result<isize, ParseError> {
+  let mut result = 0;
+  for (col, ch) in s.char_indices() {
+    let digit = match ch {
+      '0' => 0,
+      '1' => 1,
+      '2' => 2,
+      '-' => -1,
+      '=' => -2,
+      _ => {
+        let span = col..col + ch.len_utf8();
+        return Err(ParseError::new("expected SNAFU digit", &s[span.clone()]).with_span(span));
+      }
+    };
+    result *= 5;
+    result += digit;
+  }
+  Ok(result)
+}
+
+fn to_snafu(mut num: isize) -> String {
+  let mut result = String::new();
+  while num != 0 {
+    match num % 5 {
+      0 => result.push('0'),
+      1 => result.push('1'),
+      2 => result.push('2'),
+      3 => {
+        num += 5;
+        result.push('=')
+      }
+      4 => {
+        num += 5;
+        result.push('-');
+      }
+      _ => unreachable!(),
+    }
+    num /= 5;
+  }
+  result.chars().rev().collect::<String>()
+}
+
+pub struct Day25 {
+  nums: Vec<isize>,
+}
+
+impl Solution for Day25 {
+  fn parse(input: &str) -> Result<Self, ParseError> {
+    Ok(Day25 {
+      nums: parse_lines(input, from_snafu)?,
+    })
+  }
+
+  fn part1(&self) -> String {
+    to_snafu(self.nums.iter().sum::<isize>())
+  }
+
+  /// There is no second puzzle on the last day.
+  fn part2(&self) -> String {
+    String::new()
+  }
+}
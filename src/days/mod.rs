@@ -0,0 +1,59 @@
+mod day01;
+mod day02;
+mod day03;
+mod day04;
+mod day05;
+mod day06;
+mod day07;
+mod day08;
+mod day09;
+mod day10;
+mod day11;
+mod day12;
+mod day13;
+mod day14;
+mod day15;
+mod day16;
+mod day17;
+mod day18;
+mod day19;
+mod day20;
+mod day21;
+mod day22;
+mod day23;
+mod day24;
+mod day25;
+
+use crate::Day;
+
+pub const DAYS: [Day; 25] = [
+  Day::new::<day01::Day01>(1),
+  Day::new::<day02::Day02>(2),
+  Day::new::<day03::Day03>(3),
+  Day::new::<day04::Day04>(4),
+  Day::new::<day05::Day05>(5),
+  Day::new::<day06::Day06>(6),
+  Day::new::<day07::Day07>(7),
+  Day::new::<day08::Day08>(8),
+  Day::new::<day09::Day09>(9),
+  Day::new::<day10::Day10>(10),
+  Day::new::<day11::Day11>(11),
+  Day::new::<day12::Day12>(12),
+  Day::new::<day13::Day13>(13),
+  Day::new::<day14::Day14>(14),
+  Day::new::<day15::Day15>(15),
+  Day::new::<day16::Day16>(16),
+  Day::new::<day17::Day17>(17),
+  Day::new::<day18::Day18>(18),
+  Day::new::<day19::Day19>(19),
+  Day::new::<day20::Day20>(20),
+  Day::new::<day21::Day21>(21),
+  Day::new::<day22::Day22>(22),
+  Day::new::<day23::Day23>(23),
+  Day::new::<day24::Day24>(24),
+  Day::new::<day25::Day25>(25),
+];
+
+pub fn find(day: usize) -> Option<Day> {
+  DAYS.iter().find(|d| d.day == day).copied()
+}
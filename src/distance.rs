@@ -0,0 +1,145 @@
+use std::collections::{BinaryHeap, VecDeque};
+
+use crate::visualize::{Channel, Color};
+use crate::{Grid, Pos2};
+
+/// Distances from the nearest source to every position of a grid, see `Grid::distance_field`.
+#[derive(Clone)]
+pub struct DistanceField {
+  /// Cost of the cheapest path from any of the sources, `None` if not reachable.
+  pub distances: Grid<Option<usize>>,
+  /// Previous position on the cheapest path, `None` for sources and unreachable positions.
+  pub preds: Grid<Option<Pos2>>,
+}
+
+#[derive(PartialEq, Eq)]
+struct QueueItem {
+  cost: usize,
+  pos: Pos2,
+}
+
+impl Ord for QueueItem {
+  fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+    other.cost.cmp(&self.cost).then_with(|| self.pos.cmp(&other.pos))
+  }
+}
+
+impl PartialOrd for QueueItem {
+  fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+    Some(self.cmp(other))
+  }
+}
+
+impl DistanceField {
+  fn new<T: Clone>(grid: &Grid<T>) -> DistanceField {
+    DistanceField {
+      distances: grid.map(|_| None),
+      preds: grid.map(|_| None),
+    }
+  }
+
+  pub fn distance(&self, pos: Pos2) -> Option<usize> {
+    self.distances[pos]
+  }
+
+  /// Cheapest path from the nearest source to the position (both inclusive).
+  pub fn path_to(&self, pos: Pos2) -> Option<Vec<Pos2>> {
+    self.distances[pos]?;
+    let mut path = vec![pos];
+    let mut pos = pos;
+    while let Some(pred) = self.preds[pos] {
+      path.push(pred);
+      pos = pred;
+    }
+    path.reverse();
+    Some(path)
+  }
+
+  /// Draw the distances as a gradient from blue (nearest) to red (farthest), leaving unreachable
+  /// positions black.
+  pub fn draw(&self, channel: &Channel) {
+    let max = self.distances.every_pos().filter_map(|pos| self.distances[pos]).max();
+    let max = max.unwrap_or(0).max(1);
+    channel.draw_grid(&self.distances, |distance| match distance {
+      Some(distance) => gradient(*distance, max),
+      None => (0, 0, 0),
+    });
+  }
+}
+
+fn gradient(value: usize, max: usize) -> Color {
+  let red = (255 * value / max) as u8;
+  (red, 0, 255 - red)
+}
+
+impl<T: Clone + PartialEq> Grid<T> {
+  /// Breadth-first search from all sources at once, every step costs one. The step function tells
+  /// if it is possible to move from the first position to the second one.
+  pub fn distance_field_bfs<M: IntoIterator<Item = Pos2>>(
+    &self,
+    sources: impl IntoIterator<Item = Pos2>,
+    moves_fn: impl Fn(&Self, Pos2) -> M,
+    step_fn: impl Fn(&Self, Pos2, Pos2) -> bool,
+  ) -> DistanceField {
+    let mut field = DistanceField::new(self);
+    let mut queue = VecDeque::new();
+    for source in sources {
+      if field.distances[source].is_none() {
+        field.distances[source] = Some(0);
+        queue.push_back(source);
+      }
+    }
+    while let Some(pos) = queue.pop_front() {
+      let distance = field.distances[pos].unwrap();
+      for next in moves_fn(self, pos) {
+        let Some(next) = self.move_to(pos, next) else {
+          continue;
+        };
+        if field.distances[next].is_none() && step_fn(self, pos, next) {
+          field.distances[next] = Some(distance + 1);
+          field.preds[next] = Some(pos);
+          queue.push_back(next);
+        }
+      }
+    }
+    field
+  }
+
+  /// Dijkstra search from all sources at once. The cost function gives the cost of moving from the
+  /// first position to the second one, `None` if it is not possible.
+  pub fn distance_field<M: IntoIterator<Item = Pos2>>(
+    &self,
+    sources: impl IntoIterator<Item = Pos2>,
+    moves_fn: impl Fn(&Self, Pos2) -> M,
+    cost_fn: impl Fn(&Self, Pos2, Pos2) -> Option<usize>,
+  ) -> DistanceField {
+    let mut field = DistanceField::new(self);
+    let mut queue = BinaryHeap::new();
+    for source in sources {
+      field.distances[source] = Some(0);
+      queue.push(QueueItem { cost: 0, pos: source });
+    }
+    while let Some(QueueItem { cost, pos }) = queue.pop() {
+      if field.distances[pos] != Some(cost) {
+        // Already visited with a lower cost
+        continue;
+      }
+      for next in moves_fn(self, pos) {
+        let Some(next) = self.move_to(pos, next) else {
+          continue;
+        };
+        if let Some(step) = cost_fn(self, pos, next) {
+          if field.distances[next].is_none_or(|best| cost + step < best) {
+            field.distances[next] = Some(cost + step);
+            field.preds[next] = Some(pos);
+            queue.push(QueueItem {
+              cost: cost + step,
+              pos: next,
+            });
+          }
+        }
+      }
+    }
+    field
+  }
+}
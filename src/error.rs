@@ -0,0 +1,114 @@
+use pest::error::LineColLocation;
+use pest::iterators::Pair;
+use pest::RuleType;
+use std::fmt::Display;
+use std::ops::Range;
+use std::str::FromStr;
+
+/// Error while parsing the puzzle input.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+  pub message: String,
+  /// The offending text.
+  pub text: String,
+  /// Line in the input (1-based), if known.
+  pub line: Option<usize>,
+  /// Byte range of the offending text within the line, if known.
+  pub span: Option<Range<usize>>,
+}
+
+impl ParseError {
+  pub fn new(message: impl Into<String>, text: &str) -> ParseError {
+    ParseError {
+      message: message.into(),
+      text: text.to_owned(),
+      line: None,
+      span: None,
+    }
+  }
+
+  /// Error for the text matched by the pest pair, located at the pair span.
+  pub fn from_pair<R: RuleType>(message: impl Into<String>, pair: &Pair<R>) -> ParseError {
+    let span = pair.as_span();
+    let (line, column) = span.start_pos().line_col();
+    let len = span.as_str().lines().next().unwrap_or("").len();
+    ParseError {
+      line: Some(line),
+      span: Some(column - 1..column - 1 + len),
+      ..ParseError::new(message, span.as_str())
+    }
+  }
+
+  /// Set the byte range of the offending text within its line.
+  pub fn with_span(mut self, span: Range<usize>) -> ParseError {
+    self.span = Some(span);
+    self
+  }
+
+  /// Attach the line the error happened at, unless already known. If the offending text is found
+  /// in the line, its position is used as the error span.
+  pub fn at_line(mut self, line: usize, line_text: &str) -> ParseError {
+    if self.line.is_none() {
+      self.line = Some(line);
+      if self.span.is_none() && !self.text.is_empty() {
+        self.span = line_text
+          .find(self.text.as_str())
+          .map(|start| start..start + self.text.len());
+      }
+    }
+    self
+  }
+
+  /// Shift the line number by the given offset, for errors in a fragment of the whole input.
+  pub fn offset_lines(mut self, offset: usize) -> ParseError {
+    self.line = self.line.map(|line| line + offset);
+    self
+  }
+}
+
+impl Display for ParseError {
+  fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    match (self.line, &self.span) {
+      (Some(line), Some(span)) => write!(f, "line {}, column {}: ", line, span.start + 1)?,
+      (Some(line), None) => write!(f, "line {}: ", line)?,
+      _ => {}
+    }
+    write!(f, "{} in '{}'", self.message, self.text)
+  }
+}
+
+impl std::error::Error for ParseError {}
+
+impl<R: RuleType> From<pest::error::Error<R>> for ParseError {
+  fn from(err: pest::error::Error<R>) -> Self {
+    let (line, column) = match err.line_col {
+      LineColLocation::Pos(pos) => pos,
+      LineColLocation::Span(start, _) => start,
+    };
+    ParseError {
+      line: Some(line),
+      span: Some(column - 1..column),
+      ..ParseError::new(err.variant.message(), err.line())
+    }
+  }
+}
+
+/// Parse the text as a value, keeping the offending text in the error.
+pub fn parse_value<T: FromStr>(text: &str) -> Result<T, ParseError>
+where
+  T::Err: Display,
+{
+  text.parse::<T>().map_err(|err| ParseError::new(err.to_string(), text))
+}
+
+/// Parse every line of the input, attaching the line number to the errors.
+pub fn parse_lines<'s, T>(
+  input: &'s str,
+  mut parse_fn: impl FnMut(&'s str) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+  input
+    .lines()
+    .enumerate()
+    .map(|(idx, line)| parse_fn(line).map_err(|err| err.at_line(idx + 1, line)))
+    .collect()
+}
@@ -0,0 +1,748 @@
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap};
+use std::ops::{Index, IndexMut};
+
+use crate::{Dir2, Pos2};
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum BoundsBehavior<T = u8> {
+  /// Panic when accessing out of bounds
+  Panic,
+  /// Writes resize map as necessary, reads return a default value.
+  Grow { default: T },
+  /// Ignore writes, reads return a default value.
+  Abyss { default: T, nothing: T },
+  /// Positions out of bounds wrap around to the other side, as if the map was a torus.
+  Wrap,
+  /// Moving onto a blank cell or out of bounds continues from the other end of the non-blank cells
+  /// of the row or column, like walking around the day 22 map. Like `Abyss`, reads out of bounds
+  /// return blank and writes out of bounds are ignored.
+  WrapNonBlank { blank: T, nothing: T },
+}
+
+impl<T: Clone> BoundsBehavior<T> {
+  pub fn grow(value: T) -> BoundsBehavior<T> {
+    BoundsBehavior::Grow { default: value }
+  }
+
+  pub fn abyss(value: T) -> BoundsBehavior<T> {
+    BoundsBehavior::Abyss {
+      default: value.clone(),
+      nothing: value,
+    }
+  }
+
+  pub fn wrap_non_blank(blank: T) -> BoundsBehavior<T> {
+    BoundsBehavior::WrapNonBlank {
+      blank: blank.clone(),
+      nothing: blank,
+    }
+  }
+
+  fn map<U>(&self, map_fn: impl Fn(&T) -> U) -> BoundsBehavior<U> {
+    match self {
+      BoundsBehavior::Panic => BoundsBehavior::Panic,
+      BoundsBehavior::Grow { default } => BoundsBehavior::Grow {
+        default: map_fn(default),
+      },
+      BoundsBehavior::Abyss { default, nothing } => BoundsBehavior::Abyss {
+        default: map_fn(default),
+        nothing: map_fn(nothing),
+      },
+      BoundsBehavior::Wrap => BoundsBehavior::Wrap,
+      BoundsBehavior::WrapNonBlank { blank, nothing } => BoundsBehavior::WrapNonBlank {
+        blank: map_fn(blank),
+        nothing: map_fn(nothing),
+      },
+    }
+  }
+}
+
+/// Rectangular map of arbitrary cells, addressed by `Pos2`.
+#[derive(Clone)]
+pub struct Grid<T> {
+  /// Allocated cells, row by row. Growing grids allocate more than the map bounds, so they can grow
+  /// without moving the cells every time.
+  cells: Vec<T>,
+  /// Buffer for the next state of the cells in `step_update`.
+  tmp: Vec<T>,
+  /// Top left corner of the allocated cells.
+  origin: Pos2,
+  /// Width of the allocated cells.
+  stride: usize,
+  bounds: BoundsBehavior<T>,
+  /// Top left corner of the map (inclusize)
+  top_left: Pos2,
+  /// Bottom right corner of the map (inclusive)
+  bottom_right: Pos2,
+}
+
+#[derive(PartialEq, Eq, Clone, Copy)]
+struct PathState {
+  pos: Pos2,
+  cost: usize,
+  /// Cost so far plus the estimated cost to the target.
+  estimate: usize,
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum VisitKind {
+  Consider,
+  Visit,
+}
+
+impl Ord for PathState {
+  fn cmp(&self, other: &Self) -> Ordering {
+    other
+      .estimate
+      .cmp(&self.estimate)
+      .then_with(|| self.pos.cmp(&other.pos))
+  }
+}
+
+impl PartialOrd for PathState {
+  fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    Some(self.cmp(other))
+  }
+}
+
+impl<T: Clone> Index<Pos2> for Grid<T> {
+  type Output = T;
+
+  fn index(&self, pos: Pos2) -> &Self::Output {
+    if self.is_in_bounds(pos) {
+      &self.cells[self.cell_index(pos)]
+    } else {
+      match self.bounds {
+        BoundsBehavior::Panic => panic!("{} is out of bounds", pos),
+        BoundsBehavior::Abyss { ref default, .. } => default,
+        BoundsBehavior::Grow { ref default } => default,
+        BoundsBehavior::Wrap => &self.cells[self.cell_index(self.wrap_pos(pos))],
+        BoundsBehavior::WrapNonBlank { ref blank, .. } => blank,
+      }
+    }
+  }
+}
+
+impl<T: Clone> IndexMut<Pos2> for Grid<T> {
+  fn index_mut(&mut self, pos: Pos2) -> &mut Self::Output {
+    if !self.is_in_bounds(pos) {
+      match self.bounds {
+        BoundsBehavior::Panic => panic!("{} is out of bounds", pos),
+        BoundsBehavior::Abyss { ref mut nothing, .. } | BoundsBehavior::WrapNonBlank { ref mut nothing, .. } => {
+          return nothing
+        }
+        BoundsBehavior::Wrap => {
+          let idx = self.cell_index(self.wrap_pos(pos));
+          return &mut self.cells[idx];
+        }
+        BoundsBehavior::Grow { ref default } => {
+          let default = default.clone();
+          let (top_left, bottom_right) = if self.is_empty() {
+            (pos, pos)
+          } else {
+            (
+              Pos2::new(self.top_left.x.min(pos.x), self.top_left.y.min(pos.y)),
+              Pos2::new(self.bottom_right.x.max(pos.x), self.bottom_right.y.max(pos.y)),
+            )
+          };
+          self.reserve(top_left, bottom_right, default.clone());
+          self.fill_grown(top_left, bottom_right, default);
+          self.top_left = top_left;
+          self.bottom_right = bottom_right;
+        }
+      }
+    }
+    let idx = self.cell_index(pos);
+    &mut self.cells[idx]
+  }
+}
+
+impl<T: Clone> Grid<T> {
+  /// Create the grid from the rows of cells, top to bottom. All rows must be of the same length.
+  pub fn from_rows(map: Vec<Vec<T>>) -> Self {
+    let width = map.first().map_or(0, |row| row.len());
+    assert!(
+      map.iter().all(|row| row.len() == width),
+      "rows must be of the same length"
+    );
+    let bottom_right = if width == 0 {
+      Pos2::new(-1, -1)
+    } else {
+      Pos2::new((width as isize) - 1, (map.len() as isize) - 1)
+    };
+    Grid {
+      cells: map.into_iter().flatten().collect(),
+      tmp: Vec::new(),
+      origin: Pos2::zero(),
+      stride: width,
+      bounds: BoundsBehavior::Panic,
+      top_left: Pos2::zero(),
+      bottom_right,
+    }
+  }
+
+  pub fn empty(bounds: BoundsBehavior<T>) -> Self {
+    Grid {
+      cells: Vec::new(),
+      tmp: Vec::new(),
+      origin: Pos2::zero(),
+      stride: 0,
+      bounds,
+      top_left: Pos2::zero(),
+      bottom_right: Pos2::new(-1, -1),
+    }
+  }
+
+  fn is_empty(&self) -> bool {
+    self.top_left.x > self.bottom_right.x || self.top_left.y > self.bottom_right.y
+  }
+
+  fn cell_index(&self, pos: Pos2) -> usize {
+    (pos.y - self.origin.y) as usize * self.stride + (pos.x - self.origin.x) as usize
+  }
+
+  /// Allocated cells of the row within the map bounds.
+  fn row(&self, y: isize) -> &[T] {
+    let start = self.cell_index(Pos2::new(self.top_left.x, y));
+    &self.cells[start..start + self.dims().x as usize]
+  }
+
+  /// Make sure the cells between the corners are allocated. When allocating more, at least doubles
+  /// the allocation in the direction it grows to, so growing cell by cell takes amortized constant
+  /// time per cell.
+  fn reserve(&mut self, top_left: Pos2, bottom_right: Pos2, default: T) {
+    let height = self.cells.len().checked_div(self.stride).unwrap_or(0);
+    let end = self.origin + Pos2::new(self.stride as isize, height as isize);
+    let allocated =
+      top_left.x >= self.origin.x && top_left.y >= self.origin.y && bottom_right.x < end.x && bottom_right.y < end.y;
+    if allocated {
+      return;
+    }
+
+    let (new_origin, new_end) = if self.stride == 0 {
+      (top_left, bottom_right + Pos2::new(1, 1))
+    } else {
+      let (width, height) = (self.stride as isize, height as isize);
+      let grow = |low: isize, high: isize, origin: isize, end: isize, size: isize| {
+        let low = if low < origin { low.min(origin - size) } else { origin };
+        let high = if high >= end { (high + 1).max(end + size) } else { end };
+        (low, high)
+      };
+      let (x0, x1) = grow(top_left.x, bottom_right.x, self.origin.x, end.x, width);
+      let (y0, y1) = grow(top_left.y, bottom_right.y, self.origin.y, end.y, height);
+      (Pos2::new(x0, y0), Pos2::new(x1, y1))
+    };
+    let new_stride = (new_end.x - new_origin.x) as usize;
+    let new_height = (new_end.y - new_origin.y) as usize;
+    let mut cells = vec![default; new_stride * new_height];
+    if self.stride != 0 {
+      let offset = self.origin - new_origin;
+      for (y, row) in self.cells.chunks(self.stride).enumerate() {
+        let start = (offset.y as usize + y) * new_stride + offset.x as usize;
+        cells[start..start + self.stride].clone_from_slice(row);
+      }
+    }
+    self.cells = cells;
+    self.origin = new_origin;
+    self.stride = new_stride;
+  }
+
+  /// Reset the cells which are about to be added to the map bounds, as they could have been left
+  /// over by a different default.
+  fn fill_grown(&mut self, top_left: Pos2, bottom_right: Pos2, default: T) {
+    for y in top_left.y..=bottom_right.y {
+      let ranges = if self.is_empty() || y < self.top_left.y || y > self.bottom_right.y {
+        [(top_left.x, bottom_right.x), (0, -1)]
+      } else {
+        [
+          (top_left.x, self.top_left.x - 1),
+          (self.bottom_right.x + 1, bottom_right.x),
+        ]
+      };
+      for (from, to) in ranges {
+        if from <= to {
+          let start = self.cell_index(Pos2::new(from, y));
+          self.cells[start..=start + (to - from) as usize].fill(default.clone());
+        }
+      }
+    }
+  }
+
+  /// Set the "default" value for elements outside of the map bounds.
+  pub fn with_bounds(mut self, bounds: BoundsBehavior<T>) -> Self {
+    self.bounds = bounds;
+    self
+  }
+
+  /// Move the map so its top left corner is at the given position.
+  pub fn with_offset(mut self, top_left: Pos2) -> Self {
+    let delta = top_left - self.top_left;
+    self.origin += delta;
+    self.top_left += delta;
+    self.bottom_right += delta;
+    self
+  }
+
+  pub fn bounds(&self) -> &BoundsBehavior<T> {
+    &self.bounds
+  }
+
+  pub fn is_in_bounds(&self, pos: Pos2) -> bool {
+    pos.inside_rect(self.top_left, self.bottom_right)
+  }
+
+  /// Wrap the position around the map, as if the map was a torus.
+  pub fn wrap_pos(&self, pos: Pos2) -> Pos2 {
+    assert!(!self.is_empty(), "cannot wrap around an empty map");
+    let dims = self.dims();
+    Pos2::new(
+      self.top_left.x + (pos.x - self.top_left.x).rem_euclid(dims.x),
+      self.top_left.y + (pos.y - self.top_left.y).rem_euclid(dims.y),
+    )
+  }
+
+  /// Derive a grid of the same shape by mapping every cell. Bounds defaults are mapped, too.
+  pub fn map<U: Clone>(&self, map_fn: impl Fn(&T) -> U) -> Grid<U> {
+    Grid {
+      cells: self.cells.iter().map(&map_fn).collect(),
+      tmp: Vec::new(),
+      origin: self.origin,
+      stride: self.stride,
+      bounds: self.bounds.map(&map_fn),
+      top_left: self.top_left,
+      bottom_right: self.bottom_right,
+    }
+  }
+
+  /// Derive a grid by combining the cells of two grids of the same shape. The result panics when
+  /// accessed out of bounds, use `with_bounds` to change that.
+  pub fn zip<U: Clone, V: Clone>(&self, other: &Grid<U>, zip_fn: impl Fn(&T, &U) -> V) -> Grid<V> {
+    assert!(
+      self.top_left == other.top_left && self.bottom_right == other.bottom_right,
+      "grids must be of the same shape"
+    );
+    let width = if self.is_empty() { 0 } else { self.dims().x as usize };
+    Grid {
+      cells: self.every_pos().map(|pos| zip_fn(&self[pos], &other[pos])).collect(),
+      tmp: Vec::new(),
+      origin: self.top_left,
+      stride: width,
+      bounds: BoundsBehavior::Panic,
+      top_left: self.top_left,
+      bottom_right: self.bottom_right,
+    }
+  }
+
+  /// Update all cells at once, computing the next value of every cell from the current state. Returns
+  /// if any cell has changed. See `Automaton` for running many steps.
+  pub fn step_update(&mut self, update_cb: impl Fn(&Self, Pos2) -> T) -> bool
+  where
+    T: PartialEq,
+  {
+    let mut changes = false;
+    let mut tmp = std::mem::take(&mut self.tmp);
+    tmp.clone_from(&self.cells);
+    for pos in self.every_pos() {
+      let updated = update_cb(self, pos);
+      if self[pos] != updated {
+        changes = true;
+      }
+      tmp[self.cell_index(pos)] = updated;
+    }
+    self.tmp = std::mem::replace(&mut self.cells, tmp);
+    changes
+  }
+
+  pub fn count(&self, value: T) -> usize
+  where
+    T: PartialEq,
+  {
+    if self.is_empty() {
+      return 0;
+    }
+    (self.top_left.y..=self.bottom_right.y)
+      .map(|y| self.row(y).iter().filter(|other| value == **other).count())
+      .sum::<usize>()
+  }
+
+  pub fn top_left(&self) -> Pos2 {
+    self.top_left
+  }
+
+  pub fn bottom_right(&self) -> Pos2 {
+    self.bottom_right
+  }
+
+  pub fn dims(&self) -> Pos2 {
+    self.bottom_right - self.top_left + Dir2::new(1, 1)
+  }
+
+  pub fn every_pos(&self) -> impl Iterator<Item = Pos2> {
+    Pos2::iter_rect(self.top_left, self.bottom_right)
+  }
+}
+
+impl<T: Clone + PartialEq> Grid<T> {
+  /// Position reached by moving from the position in the direction, honouring the wrapping bounds
+  /// behaviors. `None` if the move leaves the map.
+  pub fn neighbor(&self, pos: Pos2, dir: Dir2) -> Option<Pos2> {
+    let next = pos + dir;
+    match self.bounds {
+      BoundsBehavior::Wrap => Some(self.wrap_pos(next)),
+      BoundsBehavior::WrapNonBlank { ref blank, .. } => {
+        // Longer moves are made as single steps along the line of the move, each wrapping around
+        let count = gcd(dir.dx.unsigned_abs(), dir.dy.unsigned_abs()).max(1) as isize;
+        let step = Dir2::new(dir.dx / count, dir.dy / count);
+        Some((0..count).fold(pos, |pos, _| self.wrap_step(pos, step, blank)))
+      }
+      _ => Some(next).filter(|next| self.is_in_bounds(*next)),
+    }
+  }
+
+  /// Position reached by a single step of `WrapNonBlank` bounds: the next cell if it is not blank,
+  /// otherwise the first non-blank cell from the other end of the line of the step.
+  fn wrap_step(&self, pos: Pos2, step: Dir2, blank: &T) -> Pos2 {
+    let next = pos + step;
+    if self.is_in_bounds(next) && self[next] != *blank {
+      return next;
+    }
+    if step == Dir2::new(0, 0) {
+      return pos;
+    }
+    let mut far_end = pos;
+    while self.is_in_bounds(far_end + -step) {
+      far_end += -step;
+    }
+    std::iter::successors(Some(far_end), |pos| Some(*pos + step))
+      .take_while(|pos| *pos != next)
+      .find(|pos| self[*pos] != *blank)
+      .unwrap_or(pos)
+  }
+
+  /// Positions reached by moving from the position in 4 directions, see `neighbor`.
+  pub fn neighbors(&self, pos: Pos2) -> impl Iterator<Item = Pos2> + '_ {
+    MOVES_4.iter().filter_map(move |dir| self.neighbor(pos, *dir))
+  }
+
+  /// Cast a ray in a given direction and find first position matching the condition. The ray stops
+  /// at the map bounds, or once it gets around a wrapping map.
+  pub fn cast_find(&self, pos: Pos2, dir: Dir2, match_fn: impl Fn(&Self, Pos2) -> bool) -> Option<Pos2> {
+    self.ray(pos, dir).find(|pos| match_fn(self, *pos))
+  }
+
+  /// Positions reached by moving from the position in the direction over and over, see `neighbor`.
+  /// Stops at the map bounds, or once it gets around a wrapping map.
+  fn ray(&self, pos: Pos2, dir: Dir2) -> impl Iterator<Item = Pos2> + '_ {
+    let dims = self.dims();
+    std::iter::successors(Some(pos), move |prev| self.neighbor(*prev, dir))
+      .skip(1)
+      .take_while(move |next| *next != pos)
+      .take((dims.x * dims.y).max(0) as usize)
+  }
+
+  pub fn find_path(
+    &self,
+    start: Pos2,
+    target_fn: impl Fn(&Self, Pos2) -> bool,
+    cost_fn: impl Fn(&Self, Pos2, Pos2) -> Option<usize>,
+  ) -> Option<PathResult> {
+    self.find_path_cb(start, target_fn, cost_fn, |_, _, _, _| {})
+  }
+
+  /// Find the cheapest path from the start to any of the targets, moving in 4 directions. Returns
+  /// `None` if no target is reachable.
+  pub fn find_path_cb(
+    &self,
+    start: Pos2,
+    target_fn: impl Fn(&Self, Pos2) -> bool,
+    cost_fn: impl Fn(&Self, Pos2, Pos2) -> Option<usize>,
+    visit_fn: impl FnMut(&Self, VisitKind, Pos2, usize),
+  ) -> Option<PathResult> {
+    self.find_path_moves_cb(start, offset_moves(&MOVES_4), target_fn, cost_fn, visit_fn)
+  }
+
+  pub fn find_path_moves<M: IntoIterator<Item = Pos2>>(
+    &self,
+    start: Pos2,
+    moves_fn: impl Fn(&Self, Pos2) -> M,
+    target_fn: impl Fn(&Self, Pos2) -> bool,
+    cost_fn: impl Fn(&Self, Pos2, Pos2) -> Option<usize>,
+  ) -> Option<PathResult> {
+    self.find_path_moves_cb(start, moves_fn, target_fn, cost_fn, |_, _, _, _| {})
+  }
+
+  /// Find the cheapest path from the start to any of the targets, making the moves given by the
+  /// move generator (see `offset_moves` and `slide`). Moves leaving the map are skipped, moves over
+  /// the edge of a wrapping map end where `move_to` says. The path of the result consists of the
+  /// positions the moves end at.
+  pub fn find_path_moves_cb<M: IntoIterator<Item = Pos2>>(
+    &self,
+    start: Pos2,
+    moves_fn: impl Fn(&Self, Pos2) -> M,
+    target_fn: impl Fn(&Self, Pos2) -> bool,
+    cost_fn: impl Fn(&Self, Pos2, Pos2) -> Option<usize>,
+    visit_fn: impl FnMut(&Self, VisitKind, Pos2, usize),
+  ) -> Option<PathResult> {
+    let explored = self.search(start, moves_fn, |_, _| false, cost_fn, |_| 0, visit_fn);
+    let target = self
+      .every_pos()
+      .filter(|p| target_fn(self, *p) && explored.costs.contains_key(p))
+      .min_by_key(|p| explored.costs[p])?;
+    Some(explored.into_result(start, target))
+  }
+
+  pub fn find_path_astar<M: IntoIterator<Item = Pos2>>(
+    &self,
+    start: Pos2,
+    moves_fn: impl Fn(&Self, Pos2) -> M,
+    target_fn: impl Fn(&Self, Pos2) -> bool,
+    cost_fn: impl Fn(&Self, Pos2, Pos2) -> Option<usize>,
+    heuristic_fn: impl Fn(Pos2) -> usize,
+  ) -> Option<PathResult> {
+    self.find_path_astar_cb(start, moves_fn, target_fn, cost_fn, heuristic_fn, |_, _, _, _| {})
+  }
+
+  /// Find the cheapest path with A*, stopping at the first target reached. The heuristic estimates
+  /// the cost from the position to the nearest target, and must never overestimate it (see
+  /// `manhattan_to` and `chebyshev_to`). The search stops early, so `all_paths` of the result can
+  /// miss some of the paths.
+  pub fn find_path_astar_cb<M: IntoIterator<Item = Pos2>>(
+    &self,
+    start: Pos2,
+    moves_fn: impl Fn(&Self, Pos2) -> M,
+    target_fn: impl Fn(&Self, Pos2) -> bool,
+    cost_fn: impl Fn(&Self, Pos2, Pos2) -> Option<usize>,
+    heuristic_fn: impl Fn(Pos2) -> usize,
+    visit_fn: impl FnMut(&Self, VisitKind, Pos2, usize),
+  ) -> Option<PathResult> {
+    let explored = self.search(start, moves_fn, target_fn, cost_fn, heuristic_fn, visit_fn);
+    let target = explored.stopped?;
+    Some(explored.into_result(start, target))
+  }
+
+  /// Slide from the position in the given direction until the next position is out of bounds or
+  /// the stop condition is true for it. Returns the last position reached.
+  pub fn slide(&self, pos: Pos2, dir: Dir2, stop_fn: impl Fn(&Self, Pos2) -> bool) -> Pos2 {
+    self
+      .ray(pos, dir)
+      .take_while(|next| !stop_fn(self, *next))
+      .last()
+      .unwrap_or(pos)
+  }
+
+  /// Position a move from the position to the other one ends at, honouring the wrapping bounds
+  /// behaviors. `None` if the move leaves the map.
+  pub fn move_to(&self, pos: Pos2, next: Pos2) -> Option<Pos2> {
+    let delta = next - pos;
+    self.neighbor(pos, Dir2::new(delta.x, delta.y))
+  }
+
+  /// Run the search until the queue is exhausted or the stop condition is true for the position
+  /// taken from the queue.
+  fn search<M: IntoIterator<Item = Pos2>>(
+    &self,
+    start: Pos2,
+    moves_fn: impl Fn(&Self, Pos2) -> M,
+    stop_fn: impl Fn(&Self, Pos2) -> bool,
+    cost_fn: impl Fn(&Self, Pos2, Pos2) -> Option<usize>,
+    heuristic_fn: impl Fn(Pos2) -> usize,
+    mut visit_fn: impl FnMut(&Self, VisitKind, Pos2, usize),
+  ) -> Explored {
+    let mut explored = Explored {
+      expanded: 0,
+      costs: HashMap::new(),
+      preds: HashMap::new(),
+      stopped: None,
+    };
+    let costs = &mut explored.costs;
+    let preds = &mut explored.preds;
+    let mut queue = BinaryHeap::new();
+    queue.push(PathState {
+      pos: start,
+      cost: 0,
+      estimate: heuristic_fn(start),
+    });
+    costs.insert(start, 0);
+    while let Some(PathState { pos, cost, .. }) = queue.pop() {
+      if cost > costs[&pos] {
+        // Already visited with a lower cost
+        continue;
+      }
+      visit_fn(self, VisitKind::Visit, pos, cost);
+      if stop_fn(self, pos) {
+        explored.stopped = Some(pos);
+        break;
+      }
+      explored.expanded += 1;
+
+      for next in moves_fn(self, pos) {
+        let Some(next) = self.move_to(pos, next) else {
+          continue;
+        };
+        if let Some(next_cost) = cost_fn(self, pos, next) {
+          let best = costs.get(&next).copied().unwrap_or(usize::MAX);
+          if cost + next_cost < best {
+            costs.insert(next, cost + next_cost);
+            preds.insert(next, vec![pos]);
+            visit_fn(self, VisitKind::Consider, next, cost);
+            queue.push(PathState {
+              pos: next,
+              cost: cost + next_cost,
+              estimate: cost + next_cost + heuristic_fn(next),
+            });
+          } else if cost + next_cost == best && next_cost > 0 {
+            preds.entry(next).or_default().push(pos);
+          }
+        }
+      }
+    }
+    explored
+  }
+}
+
+/// Positions reached by `Grid::search`, with the position it stopped at if any.
+struct Explored {
+  expanded: usize,
+  costs: HashMap<Pos2, usize>,
+  preds: HashMap<Pos2, Vec<Pos2>>,
+  stopped: Option<Pos2>,
+}
+
+impl Explored {
+  /// Cheapest path to the target, which must have been reached.
+  fn into_result(self, start: Pos2, target: Pos2) -> PathResult {
+    PathResult {
+      cost: self.costs[&target],
+      start,
+      target,
+      expanded: self.expanded,
+      preds: self.preds,
+    }
+  }
+}
+
+fn gcd(first: usize, second: usize) -> usize {
+  if second == 0 {
+    first
+  } else {
+    gcd(second, first % second)
+  }
+}
+
+/// Moves to the 4 orthogonal neighbours.
+pub const MOVES_4: [Dir2; 4] = [Dir2::new(1, 0), Dir2::new(0, 1), Dir2::new(-1, 0), Dir2::new(0, -1)];
+
+/// Moves to the 4 orthogonal neighbours or staying in place.
+pub const MOVES_4_STAY: [Dir2; 5] = [
+  Dir2::new(1, 0),
+  Dir2::new(0, 1),
+  Dir2::new(-1, 0),
+  Dir2::new(0, -1),
+  Dir2::new(0, 0),
+];
+
+/// Moves to the 8 orthogonal and diagonal neighbours.
+pub const MOVES_8: [Dir2; 8] = [
+  Dir2::new(1, 0),
+  Dir2::new(1, 1),
+  Dir2::new(0, 1),
+  Dir2::new(-1, 1),
+  Dir2::new(-1, 0),
+  Dir2::new(-1, -1),
+  Dir2::new(0, -1),
+  Dir2::new(1, -1),
+];
+
+/// Chess knight jumps.
+pub const MOVES_KNIGHT: [Dir2; 8] = [
+  Dir2::new(1, 2),
+  Dir2::new(2, 1),
+  Dir2::new(2, -1),
+  Dir2::new(1, -2),
+  Dir2::new(-1, -2),
+  Dir2::new(-2, -1),
+  Dir2::new(-2, 1),
+  Dir2::new(-1, 2),
+];
+
+/// Move generator for `find_path_moves`, moving by each of the offsets.
+pub fn offset_moves<'a, T>(offsets: &'a [Dir2]) -> impl Fn(&Grid<T>, Pos2) -> OffsetMoves<'a> + 'a {
+  move |_, pos| OffsetMoves {
+    pos,
+    offsets: offsets.iter(),
+  }
+}
+
+/// Positions reached by moving by each of the offsets, see `offset_moves`.
+pub struct OffsetMoves<'a> {
+  pos: Pos2,
+  offsets: std::slice::Iter<'a, Dir2>,
+}
+
+impl Iterator for OffsetMoves<'_> {
+  type Item = Pos2;
+
+  fn next(&mut self) -> Option<Pos2> {
+    self.offsets.next().map(|dir| self.pos + *dir)
+  }
+}
+
+/// Heuristic for `find_path_astar` when moving in 4 directions with the cost of at least one.
+pub fn manhattan_to(target: Pos2) -> impl Fn(Pos2) -> usize {
+  move |pos| (target - pos).manhattan() as usize
+}
+
+/// Heuristic for `find_path_astar` when moving in 8 directions with the cost of at least one.
+pub fn chebyshev_to(target: Pos2) -> impl Fn(Pos2) -> usize {
+  move |pos| (target - pos).chebyshev() as usize
+}
+
+/// Cheapest path found by `find_path`.
+#[derive(Clone, Debug)]
+pub struct PathResult {
+  pub cost: usize,
+  pub start: Pos2,
+  /// Target the path leads to.
+  pub target: Pos2,
+  /// Amount of positions taken from the queue and expanded, a measure of the search effort.
+  pub expanded: usize,
+  /// Predecessors of every reached position on the cheapest paths to it.
+  preds: HashMap<Pos2, Vec<Pos2>>,
+}
+
+impl PathResult {
+  /// Positions on the path, from the start to the target (both inclusive).
+  pub fn path(&self) -> Vec<Pos2> {
+    let mut path = vec![self.target];
+    let mut pos = self.target;
+    while pos != self.start {
+      pos = self.preds[&pos][0];
+      path.push(pos);
+    }
+    path.reverse();
+    path
+  }
+
+  /// All paths from the start to the target of the same cost. Steps of zero cost are only
+  /// followed on the first path found to them, so there is a finite number of paths.
+  pub fn all_paths(&self) -> Vec<Vec<Pos2>> {
+    let mut paths = Vec::new();
+    let mut suffix = vec![self.target];
+    self.collect_paths(&mut suffix, &mut paths);
+    paths
+  }
+
+  fn collect_paths(&self, suffix: &mut Vec<Pos2>, paths: &mut Vec<Vec<Pos2>>) {
+    let pos = *suffix.last().unwrap();
+    if pos == self.start {
+      paths.push(suffix.iter().rev().copied().collect());
+      return;
+    }
+    for pred in &self.preds[&pos] {
+      suffix.push(*pred);
+      self.collect_paths(suffix, paths);
+      suffix.pop();
+    }
+  }
+}
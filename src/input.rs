@@ -0,0 +1,108 @@
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+/// Environment variable to override the directory the day inputs are read from.
+pub const INPUTS_ENV: &str = "AOC_INPUTS";
+
+/// Input file name which reads the input from the standard input instead.
+pub const STDIN_INPUT: &str = "-";
+
+#[derive(Debug)]
+pub enum InputError {
+  /// Failed to read the input file.
+  File { path: PathBuf, source: std::io::Error },
+  /// Failed to read the standard input.
+  Stdin(std::io::Error),
+}
+
+impl std::fmt::Display for InputError {
+  fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    match self {
+      InputError::File { path, source } => write!(f, "cannot read input '{}': {}", path.display(), source),
+      InputError::Stdin(source) => write!(f, "cannot read input from stdin: {}", source),
+    }
+  }
+}
+
+impl std::error::Error for InputError {
+  fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+    match self {
+      InputError::File { source, .. } => Some(source),
+      InputError::Stdin(source) => Some(source),
+    }
+  }
+}
+
+/// Directory holding the inputs of every day, as `dayNN/<file>`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InputDir {
+  root: PathBuf,
+}
+
+impl InputDir {
+  pub fn new(root: impl Into<PathBuf>) -> InputDir {
+    InputDir { root: root.into() }
+  }
+
+  /// Resolve the inputs directory. An explicit directory (for example, given on the command line)
+  /// takes precedence over the `AOC_INPUTS` environment variable, which takes precedence over
+  /// `src/bin` of the crate itself, so it does not matter where the binary is run from.
+  pub fn resolve(explicit: Option<&Path>) -> InputDir {
+    if let Some(dir) = explicit {
+      InputDir::new(dir)
+    } else if let Some(dir) = std::env::var_os(INPUTS_ENV) {
+      InputDir::new(dir)
+    } else {
+      InputDir::new(Path::new(env!("CARGO_MANIFEST_DIR")).join("src").join("bin"))
+    }
+  }
+
+  pub fn root(&self) -> &Path {
+    &self.root
+  }
+
+  /// Path of the input file for the given day.
+  pub fn path(&self, day: usize, file: &str) -> PathBuf {
+    self.root.join(format!("day{:02}", day)).join(file)
+  }
+
+  /// Names of all input files (`*.txt`) available for the given day, sorted.
+  pub fn files(&self, day: usize) -> Vec<String> {
+    let mut files = std::fs::read_dir(self.root.join(format!("day{:02}", day)))
+      .into_iter()
+      .flatten()
+      .filter_map(|entry| entry.ok())
+      .filter(|entry| entry.path().extension().is_some_and(|ext| ext == "txt"))
+      .filter_map(|entry| entry.file_name().into_string().ok())
+      .collect::<Vec<_>>();
+    files.sort();
+    files
+  }
+
+  /// Load the input file for the given day, or the standard input if the file is `-`. Line
+  /// endings are normalized to `\n`.
+  pub fn load(&self, day: usize, file: &str) -> Result<String, InputError> {
+    let data = if file == STDIN_INPUT {
+      let mut data = String::new();
+      std::io::stdin().read_to_string(&mut data).map_err(InputError::Stdin)?;
+      data
+    } else {
+      let path = self.path(day, file);
+      std::fs::read_to_string(&path).map_err(|source| InputError::File { path, source })?
+    };
+    Ok(normalize_newlines(data))
+  }
+}
+
+fn normalize_newlines(data: String) -> String {
+  if data.contains('\r') {
+    data.replace("\r\n", "\n")
+  } else {
+    data
+  }
+}
+
+/// Load the input file for the given day from the default inputs directory.
+pub fn input_data(day: usize, file: &str) -> Result<String, InputError> {
+  InputDir::resolve(None).load(day, file)
+}
@@ -0,0 +1,101 @@
+use crate::{Grid, ParseError, Pos2};
+
+/// Meaning of the characters of a map given in the puzzle input: every character stands either
+/// for a cell, or for an entity (like a start marker) standing on a background cell.
+#[derive(Clone)]
+pub struct Legend<C, E> {
+  /// Meaning of every ASCII character, `None` for the characters not allowed on the map.
+  entries: Vec<Option<(C, Option<E>)>>,
+}
+
+/// Map parsed with a legend, see `Legend::parse`.
+#[derive(Clone)]
+pub struct LegendMap<C, E> {
+  /// Cells of the map, with the background cells in place of the entities.
+  pub cells: Grid<C>,
+  /// Entities found on the map with their positions, left to right, then top to bottom.
+  pub entities: Vec<(Pos2, E)>,
+}
+
+impl<C: Clone, E: Clone> Default for Legend<C, E> {
+  fn default() -> Self {
+    Legend {
+      entries: vec![None; 128],
+    }
+  }
+}
+
+impl<C: Clone, E: Clone> Legend<C, E> {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// The character stands for the cell.
+  pub fn cell(mut self, ch: u8, cell: C) -> Self {
+    self.entries[usize::from(ch)] = Some((cell, None));
+    self
+  }
+
+  /// The character marks the entity, standing on the background cell.
+  pub fn entity(mut self, ch: u8, entity: E, background: C) -> Self {
+    self.entries[usize::from(ch)] = Some((background, Some(entity)));
+    self
+  }
+
+  /// Parse the map in a single pass over the text. Rows shorter than the longest one are padded
+  /// with spaces, like `CharMap::from_text` does, so they are an error unless ' ' is in the legend.
+  pub fn parse(&self, text: &str) -> Result<LegendMap<C, E>, ParseError> {
+    let width = text.lines().map(|line| line.len()).max().unwrap_or(0);
+    let mut entities = Vec::new();
+    let mut rows = Vec::new();
+    for (y, line) in text.lines().enumerate() {
+      let mut row = Vec::with_capacity(width);
+      let padding = (line.len()..width).map(|x| (x, ' '));
+      for (x, ch) in line.char_indices().chain(padding) {
+        let entry = self.entries.get(ch as usize).and_then(Option::as_ref);
+        let Some((cell, entity)) = entry else {
+          if x >= line.len() {
+            let message = format!("row is shorter than the map, expected {} characters", width);
+            return Err(ParseError::new(message, line).at_line(y + 1, line));
+          }
+          let span = x..x + ch.len_utf8();
+          return Err(
+            ParseError::new(format!("unexpected '{}' on the map", ch), &line[span.clone()])
+              .at_line(y + 1, line)
+              .with_span(span),
+          );
+        };
+        row.push(cell.clone());
+        if let Some(entity) = entity {
+          entities.push((Pos2::new(x as isize, y as isize), entity.clone()));
+        }
+      }
+      rows.push(row);
+    }
+    Ok(LegendMap {
+      cells: Grid::from_rows(rows),
+      entities,
+    })
+  }
+}
+
+impl<C, E: PartialEq> LegendMap<C, E> {
+  /// Positions of all the given entities.
+  pub fn positions<'a>(&'a self, entity: &'a E) -> impl Iterator<Item = Pos2> + 'a {
+    self
+      .entities
+      .iter()
+      .filter(move |(_, other)| other == entity)
+      .map(|(pos, _)| *pos)
+  }
+
+  /// Position of the only given entity, an error if there is none or more than one.
+  pub fn single(&self, entity: &E, name: &str) -> Result<Pos2, ParseError> {
+    let mut positions = self.positions(entity);
+    match (positions.next(), positions.next()) {
+      (Some(pos), None) => Ok(pos),
+      (None, _) => Err(ParseError::new(format!("no {} on the map", name), "")),
+      (Some(_), Some(pos)) => Err(ParseError::new(format!("second {} on the map at {}", name, pos), "")),
+    }
+  }
+}
@@ -1,55 +1,92 @@
 pub use area::*;
+pub use automaton::*;
 pub use charmap::*;
-use pest::iterators::Pairs;
+pub use components::*;
+pub use cube::*;
+pub use distance::*;
+pub use error::*;
+pub use grid::*;
+pub use input::*;
+pub use legend::*;
+use pest::iterators::{Pair, Pairs};
 use pest::RuleType;
 pub use poly::*;
 pub use pos2::*;
 pub use pos3::*;
-use std::fmt::Debug;
+pub use solution::*;
+pub use sparse::*;
+use std::fmt::Display;
 use std::str::FromStr;
+pub use template::*;
+pub use visibility::*;
 
 mod area;
+mod automaton;
+pub mod bench;
 mod charmap;
+mod components;
+mod cube;
+pub mod days;
+mod distance;
+mod error;
+mod grid;
+mod input;
+mod legend;
 mod poly;
 mod pos2;
 mod pos3;
+mod solution;
+mod sparse;
+mod template;
+mod transform;
+mod visibility;
 pub mod visualize;
 
-pub fn input_data(day: usize, file: &str) -> String {
-  std::fs::read_to_string(format!("src/bin/day{:02}/{}", day, file)).unwrap()
-}
-
 pub trait PairsExtra {
-  fn next_str(&mut self) -> &str;
-  fn next_parse<T: FromStr>(&mut self) -> T
+  fn next_str(&mut self) -> Result<&str, ParseError>;
+  fn next_parse<T: FromStr>(&mut self) -> Result<T, ParseError>
   where
-    T::Err: Debug;
-  fn parse_list<T: FromStr>(&mut self) -> Vec<T>
+    T::Err: Display;
+  fn parse_list<T: FromStr>(&mut self) -> Result<Vec<T>, ParseError>
   where
-    T::Err: Debug;
+    T::Err: Display;
+}
+
+fn next_pair<'s, R: RuleType>(pairs: &mut Pairs<'s, R>) -> Result<Pair<'s, R>, ParseError> {
+  pairs
+    .next()
+    .ok_or_else(|| ParseError::new("unexpected end of input", ""))
+}
+
+fn parse_pair<T: FromStr, R: RuleType>(pair: &Pair<R>) -> Result<T, ParseError>
+where
+  T::Err: Display,
+{
+  pair
+    .as_str()
+    .parse()
+    .map_err(|err: T::Err| ParseError::from_pair(err.to_string(), pair))
 }
 
 impl<'s, R: RuleType> PairsExtra for Pairs<'s, R> {
-  fn next_str(&mut self) -> &'s str {
-    self.next().unwrap().as_str()
+  fn next_str(&mut self) -> Result<&'s str, ParseError> {
+    Ok(next_pair(self)?.as_str())
   }
 
-  fn next_parse<T: FromStr>(&mut self) -> T
+  fn next_parse<T: FromStr>(&mut self) -> Result<T, ParseError>
   where
-    T::Err: Debug,
+    T::Err: Display,
   {
-    self.next().unwrap().as_str().parse().unwrap()
+    parse_pair(&next_pair(self)?)
   }
 
-  fn parse_list<T: FromStr>(&mut self) -> Vec<T>
+  fn parse_list<T: FromStr>(&mut self) -> Result<Vec<T>, ParseError>
   where
-    T::Err: Debug,
+    T::Err: Display,
   {
-    self
-      .next()
-      .unwrap()
+    next_pair(self)?
       .into_inner()
-      .map(|item| item.as_str().parse::<T>().unwrap())
-      .collect::<Vec<_>>()
+      .map(|item| parse_pair::<T, R>(&item))
+      .collect()
   }
 }
@@ -1,6 +1,6 @@
 use std::ops::{Add, AddAssign, Neg, Sub, SubAssign};
 
-#[derive(Clone, Copy, PartialEq, Eq, Debug, PartialOrd, Ord, Hash)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug, PartialOrd, Ord, Hash, Default)]
 pub struct Pos2 {
   pub x: isize,
   pub y: isize,
@@ -67,6 +67,10 @@ impl Pos2 {
     self.x.abs() + self.y.abs()
   }
 
+  pub fn chebyshev(&self) -> isize {
+    self.x.abs().max(self.y.abs())
+  }
+
   pub fn to_uv(self) -> Pos2 {
     Pos2::new(self.x + self.y, self.x - self.y)
   }
@@ -86,7 +90,7 @@ impl Dir2 {
   pub fn all_8() -> impl Iterator<Item = Dir2> {
     (-1..=1).flat_map(move |dy| {
       (-1..=1)
-        .filter(move |dx| (*dx != 0 || dy != 0))
+        .filter(move |dx| *dx != 0 || dy != 0)
         .map(move |dx| Dir2::new(dx, dy))
     })
   }
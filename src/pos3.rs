@@ -1,6 +1,8 @@
-use std::ops::{Add, AddAssign, Sub, SubAssign};
+use std::ops::{Add, AddAssign, Neg, Sub, SubAssign};
 use std::str::FromStr;
 
+use crate::{parse_value, ParseError};
+
 #[derive(Clone, Copy, PartialEq, Eq, Debug, PartialOrd, Ord, Hash)]
 pub struct Pos3 {
   pub x: isize,
@@ -88,14 +90,31 @@ impl std::fmt::Display for Pos3 {
 }
 
 impl FromStr for Pos3 {
-  type Err = ();
+  type Err = ParseError;
 
   fn from_str(s: &str) -> Result<Self, Self::Err> {
-    let mut it = s.split(",");
-    Ok(Pos3 {
-      x: it.next().unwrap().parse().unwrap(),
-      y: it.next().unwrap().parse().unwrap(),
-      z: it.next().unwrap().parse().unwrap(),
-    })
+    let mut it = s.split(',');
+    let mut next = || {
+      it.next()
+        .ok_or_else(|| ParseError::new("expected three coordinates", s))
+        .and_then(|coord| parse_value(coord.trim()))
+    };
+    let pos = Pos3 {
+      x: next()?,
+      y: next()?,
+      z: next()?,
+    };
+    if it.next().is_some() {
+      return Err(ParseError::new("expected three coordinates", s));
+    }
+    Ok(pos)
+  }
+}
+
+impl Neg for Dir3 {
+  type Output = Dir3;
+
+  fn neg(self) -> Self::Output {
+    Dir3::new(-self.dx, -self.dy, -self.dz)
   }
 }
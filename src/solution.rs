@@ -0,0 +1,44 @@
+use crate::visualize::Channel;
+use crate::ParseError;
+
+/// Solution for a single day of the calendar.
+pub trait Solution {
+  /// Parse the puzzle input into the state shared by both parts.
+  fn parse(input: &str) -> Result<Self, ParseError>
+  where
+    Self: Sized;
+
+  fn part1(&self) -> String;
+
+  fn part2(&self) -> String;
+
+  /// Solve both parts while drawing the progress through the channel. Days without any
+  /// visualization simply solve both parts.
+  fn visualize(&self, _channel: &Channel) -> (String, String) {
+    (self.part1(), self.part2())
+  }
+}
+
+/// Entry in the calendar of solutions.
+#[derive(Clone, Copy)]
+pub struct Day {
+  pub day: usize,
+  parse_fn: fn(&str) -> Result<Box<dyn Solution>, ParseError>,
+}
+
+fn parse_boxed<S: Solution + 'static>(input: &str) -> Result<Box<dyn Solution>, ParseError> {
+  Ok(Box::new(S::parse(input)?))
+}
+
+impl Day {
+  pub const fn new<S: Solution + 'static>(day: usize) -> Day {
+    Day {
+      day,
+      parse_fn: parse_boxed::<S>,
+    }
+  }
+
+  pub fn parse(&self, input: &str) -> Result<Box<dyn Solution>, ParseError> {
+    (self.parse_fn)(input)
+  }
+}
@@ -0,0 +1,110 @@
+use std::collections::HashMap;
+use std::ops::{Index, IndexMut};
+
+use crate::{Area, BoundsBehavior, Grid, Pos2};
+
+/// Width and height of a single tile.
+const TILE: isize = 16;
+
+/// Unbounded map of cells, storing only the tiles which were written to. Every other cell holds
+/// the default value. Cells which differ from the default are "occupied".
+#[derive(Clone)]
+pub struct SparseGrid<T> {
+  /// Tiles keyed by the position of their top left corner divided by the tile size. Cells are
+  /// stored row by row.
+  tiles: HashMap<Pos2, Box<[T]>>,
+  default: T,
+}
+
+fn tile_of(pos: Pos2) -> (Pos2, usize) {
+  let tile = Pos2::new(pos.x.div_euclid(TILE), pos.y.div_euclid(TILE));
+  let idx = pos.y.rem_euclid(TILE) * TILE + pos.x.rem_euclid(TILE);
+  (tile, idx as usize)
+}
+
+impl<T: Clone + PartialEq> Index<Pos2> for SparseGrid<T> {
+  type Output = T;
+
+  fn index(&self, pos: Pos2) -> &Self::Output {
+    let (tile, idx) = tile_of(pos);
+    self.tiles.get(&tile).map_or(&self.default, |cells| &cells[idx])
+  }
+}
+
+impl<T: Clone + PartialEq> IndexMut<Pos2> for SparseGrid<T> {
+  fn index_mut(&mut self, pos: Pos2) -> &mut Self::Output {
+    let (tile, idx) = tile_of(pos);
+    let default = &self.default;
+    let cells = self
+      .tiles
+      .entry(tile)
+      .or_insert_with(|| vec![default.clone(); (TILE * TILE) as usize].into_boxed_slice());
+    &mut cells[idx]
+  }
+}
+
+impl<T: Clone + PartialEq> SparseGrid<T> {
+  pub fn new(default: T) -> Self {
+    SparseGrid {
+      tiles: HashMap::new(),
+      default,
+    }
+  }
+
+  /// Positions of all occupied cells, in no particular order.
+  pub fn occupied(&self) -> impl Iterator<Item = Pos2> + '_ {
+    self.tiles.iter().flat_map(move |(tile, cells)| {
+      cells
+        .iter()
+        .enumerate()
+        .filter(move |(_, cell)| **cell != self.default)
+        .map(move |(idx, _)| {
+          let idx = idx as isize;
+          Pos2::new(tile.x * TILE + idx % TILE, tile.y * TILE + idx / TILE)
+        })
+    })
+  }
+
+  /// Smallest area covering all occupied cells, `None` if there are none.
+  pub fn bounding_box(&self) -> Option<Area> {
+    self.occupied().fold(None, |area: Option<Area>, pos| {
+      Some(match area {
+        None => Area::new(pos, pos),
+        Some(area) => Area::new(
+          Pos2::new(area.top_left.x.min(pos.x), area.top_left.y.min(pos.y)),
+          Pos2::new(area.bottom_right.x.max(pos.x), area.bottom_right.y.max(pos.y)),
+        ),
+      })
+    })
+  }
+
+  /// Iterate all positions (left to right, then top to bottom) of the bounding box.
+  pub fn every_pos(&self) -> impl Iterator<Item = Pos2> {
+    let area = self.bounding_box();
+    area
+      .into_iter()
+      .flat_map(|area| Pos2::iter_rect(area.top_left, area.bottom_right))
+  }
+
+  /// Free the tiles which have no occupied cells left.
+  pub fn shrink(&mut self) {
+    let default = &self.default;
+    self.tiles.retain(|_, cells| cells.iter().any(|cell| cell != default));
+  }
+
+  /// Copy the cells of the bounding box into a grid, which grows with the default value.
+  pub fn to_grid(&self) -> Grid<T> {
+    let bounds = BoundsBehavior::grow(self.default.clone());
+    let Some(area) = self.bounding_box() else {
+      return Grid::empty(bounds);
+    };
+    let rows = (area.top_left.y..=area.bottom_right.y)
+      .map(|y| {
+        (area.top_left.x..=area.bottom_right.x)
+          .map(|x| self[Pos2::new(x, y)].clone())
+          .collect()
+      })
+      .collect();
+    Grid::from_rows(rows).with_offset(area.top_left).with_bounds(bounds)
+  }
+}
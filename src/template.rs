@@ -0,0 +1,141 @@
+use crate::{CharMap, Grid, Pos2};
+
+/// Base of the polynomial row hashes, computed modulo 2^64. Hash matches are always verified.
+const BASE: u64 = 0x100_0000_01b3;
+
+/// Small pattern to look for on a map, see `CharMap::find_template`. Wildcard cells match any
+/// character.
+#[derive(Clone)]
+pub struct Template {
+  cells: Grid<Option<u8>>,
+  /// Runs of non-wildcard cells of every row: offset from the start of the row, length and hash.
+  runs: Vec<Vec<(usize, usize, u64)>>,
+}
+
+impl Template {
+  /// Parse the template from text. Both the wildcard character and the missing characters at the
+  /// end of the shorter rows match any character.
+  pub fn from_text(text: &str, wildcard: u8) -> Template {
+    let width = text.lines().map(|line| line.len()).max().unwrap_or(0);
+    let rows = text
+      .lines()
+      .map(|line| {
+        let mut row: Vec<_> = line.bytes().map(|ch| Some(ch).filter(|ch| *ch != wildcard)).collect();
+        row.resize(width, None);
+        row
+      })
+      .collect();
+    Template::new(Grid::from_rows(rows))
+  }
+
+  fn new(cells: Grid<Option<u8>>) -> Template {
+    let mut runs = Vec::new();
+    for y in cells.top_left().y..=cells.bottom_right().y {
+      let row: Vec<_> = (cells.top_left().x..=cells.bottom_right().x)
+        .map(|x| cells[Pos2::new(x, y)])
+        .collect();
+      let mut row_runs = Vec::new();
+      let mut offset = 0;
+      for run in row.split(|cell| cell.is_none()) {
+        if !run.is_empty() {
+          let hash = run.iter().fold(0, |hash, cell| hash_next(hash, cell.unwrap()));
+          row_runs.push((offset, run.len(), hash));
+        }
+        offset += run.len() + 1;
+      }
+      runs.push(row_runs);
+    }
+    Template { cells, runs }
+  }
+
+  pub fn dims(&self) -> Pos2 {
+    self.cells.dims()
+  }
+
+  /// Distinct rotations and flips of the template, starting with the template itself.
+  pub fn symmetries(&self) -> Vec<Template> {
+    let mut symmetries: Vec<Template> = Vec::new();
+    for cells in self.cells.symmetries() {
+      if !symmetries.iter().any(|other| other.cells.same_cells(&cells)) {
+        symmetries.push(Template::new(cells));
+      }
+    }
+    symmetries
+  }
+
+  fn matches_at(&self, map: &CharMap, top_left: Pos2) -> bool {
+    self
+      .cells
+      .every_pos()
+      .all(|pos| self.cells[pos].is_none_or(|ch| map[top_left + pos - self.cells.top_left()] == ch))
+  }
+}
+
+fn hash_next(hash: u64, ch: u8) -> u64 {
+  hash.wrapping_mul(BASE).wrapping_add(u64::from(ch))
+}
+
+/// Prefix hashes of a row, giving the hash of any part of the row in constant time.
+struct RowHashes {
+  prefixes: Vec<u64>,
+}
+
+impl RowHashes {
+  fn new(row: impl Iterator<Item = u8>) -> RowHashes {
+    let mut prefixes = vec![0];
+    for ch in row {
+      prefixes.push(hash_next(*prefixes.last().unwrap(), ch));
+    }
+    RowHashes { prefixes }
+  }
+
+  fn hash(&self, start: usize, len: usize, powers: &[u64]) -> u64 {
+    self.prefixes[start + len].wrapping_sub(self.prefixes[start].wrapping_mul(powers[len]))
+  }
+}
+
+impl CharMap {
+  /// Top left positions of all occurrences of the template, left to right, then top to bottom.
+  /// Compares the hashes of the runs of non-wildcard template cells with the hashes of the map
+  /// rows, so every position takes constant time per run. Empty templates match nowhere.
+  pub fn find_template(&self, template: &Template) -> Vec<Pos2> {
+    let (dims, template_dims) = (self.dims(), template.dims());
+    if template_dims.x == 0 || template_dims.y == 0 || template_dims.x > dims.x || template_dims.y > dims.y {
+      return Vec::new();
+    }
+    let hashes: Vec<_> = (self.top_left().y..=self.bottom_right().y)
+      .map(|y| RowHashes::new((self.top_left().x..=self.bottom_right().x).map(|x| self[Pos2::new(x, y)])))
+      .collect();
+    let mut powers = vec![1u64; template_dims.x as usize + 1];
+    for len in 1..powers.len() {
+      powers[len] = powers[len - 1].wrapping_mul(BASE);
+    }
+
+    let mut found = Vec::new();
+    for y in 0..=(dims.y - template_dims.y) as usize {
+      for x in 0..=(dims.x - template_dims.x) as usize {
+        let hashes_match = template.runs.iter().enumerate().all(|(row, runs)| {
+          runs
+            .iter()
+            .all(|&(offset, len, hash)| hashes[y + row].hash(x + offset, len, &powers) == hash)
+        });
+        let top_left = self.top_left() + Pos2::new(x as isize, y as isize);
+        if hashes_match && template.matches_at(self, top_left) {
+          found.push(top_left);
+        }
+      }
+    }
+    found
+  }
+
+  /// Occurrences of the template rotated or flipped, see `Template::symmetries`. Every occurrence
+  /// is given by the index of the symmetry and its top left position.
+  pub fn find_template_symmetric(&self, template: &Template) -> Vec<(usize, Pos2)> {
+    let symmetries = template.symmetries();
+    let found = symmetries
+      .iter()
+      .enumerate()
+      .flat_map(|(idx, symmetry)| self.find_template(symmetry).into_iter().map(move |pos| (idx, pos)));
+    found.collect()
+  }
+}
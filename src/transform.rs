@@ -0,0 +1,96 @@
+use crate::{Area, Grid, Pos2};
+
+impl<T: Clone> Grid<T> {
+  /// Build a grid of the given dimensions, taking every cell from the position of this grid
+  /// returned by the source function. Both positions are relative to the top left corners, the
+  /// result keeps the top left corner and the bounds behavior of this grid.
+  fn remap(&self, dims: Pos2, source_fn: impl Fn(Pos2) -> Pos2) -> Grid<T> {
+    let rows = (0..dims.y)
+      .map(|y| {
+        (0..dims.x)
+          .map(|x| self[self.top_left() + source_fn(Pos2::new(x, y))].clone())
+          .collect()
+      })
+      .collect();
+    Grid::from_rows(rows)
+      .with_offset(self.top_left())
+      .with_bounds(self.bounds().clone())
+  }
+
+  /// Rotate the map clockwise by the given amount of quarter turns (negative turn it
+  /// counterclockwise). The result keeps the top left corner.
+  pub fn rotate(&self, quarter_turns: isize) -> Grid<T> {
+    let Pos2 { x: width, y: height } = self.dims();
+    match quarter_turns.rem_euclid(4) {
+      0 => self.clone(),
+      1 => self.remap(Pos2::new(height, width), |pos| Pos2::new(pos.y, height - 1 - pos.x)),
+      2 => self.remap(self.dims(), |pos| Pos2::new(width - 1 - pos.x, height - 1 - pos.y)),
+      _ => self.remap(Pos2::new(height, width), |pos| Pos2::new(width - 1 - pos.y, pos.x)),
+    }
+  }
+
+  /// Mirror the map left to right.
+  pub fn flip_horizontal(&self) -> Grid<T> {
+    let width = self.dims().x;
+    self.remap(self.dims(), |pos| Pos2::new(width - 1 - pos.x, pos.y))
+  }
+
+  /// Mirror the map top to bottom.
+  pub fn flip_vertical(&self) -> Grid<T> {
+    let height = self.dims().y;
+    self.remap(self.dims(), |pos| Pos2::new(pos.x, height - 1 - pos.y))
+  }
+
+  /// Mirror the map over the diagonal going from the top left corner.
+  pub fn transpose(&self) -> Grid<T> {
+    let dims = self.dims();
+    self.remap(Pos2::new(dims.y, dims.x), |pos| Pos2::new(pos.y, pos.x))
+  }
+
+  /// All 8 rotations and reflections of the map, starting with the map itself.
+  pub fn symmetries(&self) -> Vec<Grid<T>> {
+    let flipped = self.flip_horizontal();
+    (0..4)
+      .map(|turns| self.rotate(turns))
+      .chain((0..4).map(|turns| flipped.rotate(turns)))
+      .collect()
+  }
+
+  /// Extract the part of the map covered by the area. Positions stay the same, positions out of
+  /// bounds are read according to the bounds behavior.
+  pub fn crop(&self, area: Area) -> Grid<T> {
+    let offset = area.top_left - self.top_left();
+    self
+      .remap(area.bottom_right - area.top_left + Pos2::new(1, 1), |pos| pos + offset)
+      .with_offset(area.top_left)
+  }
+
+  /// Copy all cells of the other map, moving its top left corner to the given position. Cells
+  /// outside of the bounds are written according to the bounds behavior.
+  pub fn paste(&mut self, other: &Grid<T>, top_left: Pos2) {
+    let offset = top_left - other.top_left();
+    for pos in other.every_pos() {
+      self[pos + offset] = other[pos].clone();
+    }
+  }
+
+  /// Check if the maps have the same cells, wherever their top left corners are.
+  pub fn same_cells(&self, other: &Grid<T>) -> bool
+  where
+    T: PartialEq,
+  {
+    self.dims() == other.dims()
+      && self
+        .every_pos()
+        .zip(other.every_pos())
+        .all(|(pos, other_pos)| self[pos] == other[other_pos])
+  }
+
+  /// Check if the maps have the same cells after rotating or mirroring the other map.
+  pub fn same_cells_symmetric(&self, other: &Grid<T>) -> bool
+  where
+    T: PartialEq,
+  {
+    other.symmetries().iter().any(|symmetry| self.same_cells(symmetry))
+  }
+}
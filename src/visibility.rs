@@ -0,0 +1,72 @@
+use crate::{Dir2, Grid, Pos2, MOVES_4};
+
+/// Lines of sight of every cell of a grid in the 4 directions, see `Grid::visibility`. Directions
+/// are indexed in the order of `MOVES_4`.
+#[derive(Clone)]
+pub struct Visibility {
+  /// Whether nothing blocks the view from the cell to the edge of the grid in the direction.
+  pub visible: Grid<[bool; 4]>,
+  /// Amount of cells seen from the cell looking in the direction, up to the edge of the grid or
+  /// the first cell blocking the view (inclusive).
+  pub views: Grid<[usize; 4]>,
+}
+
+fn dir_index(dir: Dir2) -> usize {
+  MOVES_4
+    .iter()
+    .position(|other| *other == dir)
+    .unwrap_or_else(|| panic!("{:?} is not one of the 4 directions", dir))
+}
+
+impl Visibility {
+  /// Whether the cell is visible from any edge of the grid.
+  pub fn is_visible(&self, pos: Pos2) -> bool {
+    self.visible[pos].iter().any(|visible| *visible)
+  }
+
+  /// Whether the cell is visible from the edge of the grid in the direction.
+  pub fn is_visible_from(&self, pos: Pos2, dir: Dir2) -> bool {
+    self.visible[pos][dir_index(dir)]
+  }
+
+  pub fn view_distance(&self, pos: Pos2, dir: Dir2) -> usize {
+    self.views[pos][dir_index(dir)]
+  }
+}
+
+impl<T: Clone> Grid<T> {
+  /// Compute the lines of sight of every cell. The predicate tells if the second cell blocks the
+  /// view of the first one, it must behave like a comparison (for example, `other >= viewer`): a
+  /// cell not blocking the view of a cell not blocking the view of the viewer must not block it
+  /// either. Takes time linear in the amount of cells.
+  pub fn visibility(&self, blocks_fn: impl Fn(&T, &T) -> bool) -> Visibility {
+    let mut visible = self.map(|_| [false; 4]);
+    let mut views = self.map(|_| [0; 4]);
+    // Cells which could still block the view, nearest last, with their distances from the edge
+    let mut stack: Vec<(usize, Pos2)> = Vec::new();
+    for (idx, dir) in MOVES_4.into_iter().enumerate() {
+      let edge = self.every_pos().filter(|pos| !self.is_in_bounds(*pos + dir));
+      for start in edge {
+        stack.clear();
+        let line = start.cast_ray(-dir).take_while(|pos| self.is_in_bounds(*pos));
+        for (dist, pos) in line.enumerate() {
+          while let Some(&(_, other)) = stack.last() {
+            if blocks_fn(&self[pos], &self[other]) {
+              break;
+            }
+            stack.pop();
+          }
+          match stack.last() {
+            Some(&(other_dist, _)) => views[pos][idx] = dist - other_dist,
+            None => {
+              visible[pos][idx] = true;
+              views[pos][idx] = dist;
+            }
+          }
+          stack.push((dist, pos));
+        }
+      }
+    }
+    Visibility { visible, views }
+  }
+}
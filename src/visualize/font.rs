@@ -0,0 +1,104 @@
+/// Width of a single glyph, in pixels.
+const GLYPH_WIDTH: usize = 3;
+
+/// Height of a single glyph, in pixels.
+const GLYPH_HEIGHT: usize = 5;
+
+/// Tiny bitmap font for the status text. Every row is three bits, the highest bit on the left.
+/// Lowercase letters are drawn in uppercase, unknown characters as `?`.
+const GLYPHS: &[(char, [u8; GLYPH_HEIGHT])] = &[
+  ('0', [0b111, 0b101, 0b101, 0b101, 0b111]),
+  ('1', [0b010, 0b110, 0b010, 0b010, 0b111]),
+  ('2', [0b111, 0b001, 0b111, 0b100, 0b111]),
+  ('3', [0b111, 0b001, 0b111, 0b001, 0b111]),
+  ('4', [0b101, 0b101, 0b111, 0b001, 0b001]),
+  ('5', [0b111, 0b100, 0b111, 0b001, 0b111]),
+  ('6', [0b111, 0b100, 0b111, 0b101, 0b111]),
+  ('7', [0b111, 0b001, 0b001, 0b001, 0b001]),
+  ('8', [0b111, 0b101, 0b111, 0b101, 0b111]),
+  ('9', [0b111, 0b101, 0b111, 0b001, 0b111]),
+  ('A', [0b010, 0b101, 0b111, 0b101, 0b101]),
+  ('B', [0b110, 0b101, 0b110, 0b101, 0b110]),
+  ('C', [0b011, 0b100, 0b100, 0b100, 0b011]),
+  ('D', [0b110, 0b101, 0b101, 0b101, 0b110]),
+  ('E', [0b111, 0b100, 0b110, 0b100, 0b111]),
+  ('F', [0b111, 0b100, 0b110, 0b100, 0b100]),
+  ('G', [0b011, 0b100, 0b101, 0b101, 0b011]),
+  ('H', [0b101, 0b101, 0b111, 0b101, 0b101]),
+  ('I', [0b111, 0b010, 0b010, 0b010, 0b111]),
+  ('J', [0b001, 0b001, 0b001, 0b101, 0b010]),
+  ('K', [0b101, 0b101, 0b110, 0b101, 0b101]),
+  ('L', [0b100, 0b100, 0b100, 0b100, 0b111]),
+  ('M', [0b101, 0b111, 0b111, 0b101, 0b101]),
+  ('N', [0b110, 0b101, 0b101, 0b101, 0b101]),
+  ('O', [0b010, 0b101, 0b101, 0b101, 0b010]),
+  ('P', [0b110, 0b101, 0b110, 0b100, 0b100]),
+  ('Q', [0b010, 0b101, 0b101, 0b110, 0b011]),
+  ('R', [0b110, 0b101, 0b110, 0b101, 0b101]),
+  ('S', [0b011, 0b100, 0b010, 0b001, 0b110]),
+  ('T', [0b111, 0b010, 0b010, 0b010, 0b010]),
+  ('U', [0b101, 0b101, 0b101, 0b101, 0b111]),
+  ('V', [0b101, 0b101, 0b101, 0b101, 0b010]),
+  ('W', [0b101, 0b101, 0b111, 0b111, 0b101]),
+  ('X', [0b101, 0b101, 0b010, 0b101, 0b101]),
+  ('Y', [0b101, 0b101, 0b010, 0b010, 0b010]),
+  ('Z', [0b111, 0b001, 0b010, 0b100, 0b111]),
+  (' ', [0b000, 0b000, 0b000, 0b000, 0b000]),
+  (':', [0b000, 0b010, 0b000, 0b010, 0b000]),
+  ('.', [0b000, 0b000, 0b000, 0b000, 0b010]),
+  (',', [0b000, 0b000, 0b000, 0b010, 0b100]),
+  ('-', [0b000, 0b000, 0b111, 0b000, 0b000]),
+  ('+', [0b000, 0b010, 0b111, 0b010, 0b000]),
+  ('=', [0b000, 0b111, 0b000, 0b111, 0b000]),
+  ('/', [0b001, 0b001, 0b010, 0b100, 0b100]),
+  ('(', [0b010, 0b100, 0b100, 0b100, 0b010]),
+  (')', [0b010, 0b001, 0b001, 0b001, 0b010]),
+  ('%', [0b101, 0b001, 0b010, 0b100, 0b101]),
+  ('?', [0b111, 0b001, 0b010, 0b000, 0b010]),
+];
+
+fn glyph(ch: char) -> [u8; GLYPH_HEIGHT] {
+  let ch = ch.to_ascii_uppercase();
+  GLYPHS
+    .iter()
+    .find(|(c, _)| *c == ch)
+    .or_else(|| GLYPHS.iter().find(|(c, _)| *c == '?'))
+    .unwrap()
+    .1
+}
+
+/// Draw the text over the RGBA image at the top left corner, on a black background so it stays
+/// readable over any drawing. Every pixel of the font takes `scale` pixels in each direction, the
+/// text is cut at the image edges.
+pub fn draw_text(rgba: &mut [u8], width: usize, text: &str, scale: usize) {
+  if width == 0 {
+    return;
+  }
+  let height = rgba.len() / 4 / width;
+  let cols = text.chars().count() * (GLYPH_WIDTH + 1) + 1;
+  let rows = GLYPH_HEIGHT + 2;
+  let mut put = |x: usize, y: usize, value: u8| {
+    for py in y * scale..(y + 1) * scale {
+      for px in x * scale..(x + 1) * scale {
+        if px < width && py < height {
+          let idx = (py * width + px) * 4;
+          rgba[idx..idx + 4].copy_from_slice(&[value, value, value, 0xff]);
+        }
+      }
+    }
+  };
+  for y in 0..rows {
+    for x in 0..cols {
+      put(x, y, 0);
+    }
+  }
+  for (idx, ch) in text.chars().enumerate() {
+    for (row, bits) in glyph(ch).iter().enumerate() {
+      for col in 0..GLYPH_WIDTH {
+        if bits & (1 << (GLYPH_WIDTH - 1 - col)) != 0 {
+          put(1 + idx * (GLYPH_WIDTH + 1) + col, 1 + row, 0xff);
+        }
+      }
+    }
+  }
+}
@@ -0,0 +1,382 @@
+use super::{run_headless, Channel, Color, Frame, Paint, UserEvent};
+use crate::{Area, Pos2};
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::path::Path;
+use std::time::Duration;
+
+/// Header of the event log files, including the format version.
+const MAGIC: &[u8] = b"AOCVIS1\n";
+
+const TAG_DRAW: u8 = 1;
+const TAG_PIXEL: u8 = 2;
+const TAG_SLEEP: u8 = 3;
+const TAG_COLOR: u8 = 4;
+const TAG_PIXEL_RGB: u8 = 5;
+const TAG_PIXEL_INDEX: u8 = 6;
+const TAG_FILL_RECT: u8 = 7;
+const TAG_POLYLINE: u8 = 8;
+const TAG_CLEAR: u8 = 9;
+const TAG_PALETTE: u8 = 10;
+const TAG_STATUS: u8 = 11;
+
+/// Paint given as RGB.
+const PAINT_RGB: u8 = 0;
+/// Paint given as an index into the palette.
+const PAINT_INDEX: u8 = 1;
+
+/// Maximum amount of colors in the color table of the log.
+const COLOR_TABLE_SIZE: usize = 256;
+
+/// Headless backend writing every event the worker sends into a log file, to be replayed later.
+/// The worker does not actually sleep, the sleeps are logged instead. Returns the amount of
+/// events written.
+pub fn record_log(path: &Path, worker_fn: impl FnOnce(Channel) + Send + 'static) -> io::Result<usize> {
+  let mut writer = LogWriter::new(BufWriter::new(File::create(path)?))?;
+  let mut count = 0;
+  run_headless(worker_fn, |event| {
+    count += 1;
+    writer.write(&event)
+  })?;
+  writer.out.flush()?;
+  Ok(count)
+}
+
+/// Events sent by a worker, loaded from a log file.
+///
+/// The file starts with the header and the events follow back to back, each as a tag byte and the
+/// fields. Numbers are LEB128 varints, signed ones zigzag encoded. Pixel positions are relative to
+/// the previous pixel and the colors are indices into the color table, defined by the log itself
+/// as the colors are first used, so a typical update takes four bytes.
+#[derive(Clone, Debug, Default)]
+pub struct EventLog {
+  events: Vec<UserEvent>,
+}
+
+impl EventLog {
+  pub fn load(path: &Path) -> io::Result<EventLog> {
+    let mut input = LogReader {
+      input: BufReader::new(File::open(path)?),
+      last_pos: Pos2::zero(),
+      colors: Vec::new(),
+    };
+    let mut magic = [0u8; MAGIC.len()];
+    input.input.read_exact(&mut magic)?;
+    if magic != MAGIC {
+      return Err(invalid_data("not an event log"));
+    }
+    let mut events = Vec::new();
+    while let Some(event) = input.read()? {
+      events.push(event);
+    }
+    Ok(EventLog { events })
+  }
+
+  pub fn len(&self) -> usize {
+    self.events.len()
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.events.is_empty()
+  }
+
+  /// Total time the worker has slept while the log was recorded.
+  pub fn duration(&self) -> Duration {
+    self
+      .events
+      .iter()
+      .map(|event| match event {
+        UserEvent::Sleep(dur) => *dur,
+        _ => Duration::ZERO,
+      })
+      .sum()
+  }
+
+  /// Send the events to the channel again, as a worker. Sleeps are shortened by the speed factor
+  /// (`2.0` replays twice as fast). Everything drawn before the `start` (in the time of the
+  /// recording) is sent as a single frame, so the replay can start anywhere without the wait.
+  pub fn replay(&self, channel: &Channel, speed: f64, start: Duration) {
+    assert!(speed > 0.0, "replay speed must be positive");
+    let mut frame = Frame::default();
+    let mut elapsed = Duration::ZERO;
+    let mut seeking = !start.is_zero();
+    for event in &self.events {
+      if seeking {
+        if let UserEvent::Sleep(dur) = event {
+          elapsed += *dur;
+          if elapsed >= start {
+            seeking = false;
+            frame.snapshot().into_iter().for_each(|event| channel.send(event));
+          }
+        } else {
+          frame.apply(event.clone());
+        }
+        continue;
+      }
+      match event {
+        UserEvent::Sleep(dur) => channel.sleep(dur.div_f64(speed)),
+        _ => channel.send(event.clone()),
+      }
+    }
+    // Seeking past the end shows the final state
+    if seeking {
+      frame.snapshot().into_iter().for_each(|event| channel.send(event));
+    }
+  }
+}
+
+struct LogReader<R: Read> {
+  input: R,
+  last_pos: Pos2,
+  colors: Vec<Color>,
+}
+
+impl<R: Read> LogReader<R> {
+  /// Read the next event, `None` at the end of the log.
+  fn read(&mut self) -> io::Result<Option<UserEvent>> {
+    loop {
+      let mut tag = [0u8];
+      if self.input.read(&mut tag)? == 0 {
+        return Ok(None);
+      }
+      let event = match tag[0] {
+        TAG_DRAW => {
+          let top_left = self.read_pos()?;
+          let bottom_right = self.read_pos()?;
+          let dims = bottom_right - top_left;
+          if dims.x < 0 || dims.y < 0 {
+            return Err(invalid_data("invalid frame size"));
+          }
+          let mut rgb = vec![0; (dims.x as usize + 1) * (dims.y as usize + 1) * 3];
+          self.input.read_exact(&mut rgb)?;
+          let framebuf = rgb.chunks(3).flat_map(|c| [c[0], c[1], c[2], 0xff]).collect();
+          UserEvent::ResizeAndDraw {
+            top_left,
+            bottom_right,
+            framebuf,
+          }
+        }
+        TAG_COLOR => {
+          if self.colors.len() == COLOR_TABLE_SIZE {
+            return Err(invalid_data("color table is full"));
+          }
+          let color = self.read_color()?;
+          self.colors.push(color);
+          continue;
+        }
+        tag @ (TAG_PIXEL | TAG_PIXEL_RGB | TAG_PIXEL_INDEX) => {
+          let delta = self.read_pos()?;
+          self.last_pos += delta;
+          let color = match tag {
+            TAG_PIXEL => {
+              let idx = self.read_byte()?;
+              Paint::Rgb(
+                *self
+                  .colors
+                  .get(usize::from(idx))
+                  .ok_or_else(|| invalid_data("undefined color"))?,
+              )
+            }
+            TAG_PIXEL_RGB => Paint::Rgb(self.read_color()?),
+            _ => Paint::Index(self.read_byte()?),
+          };
+          UserEvent::Pixel {
+            pos: self.last_pos,
+            color,
+          }
+        }
+        TAG_FILL_RECT => UserEvent::FillRect {
+          area: Area::new(self.read_pos()?, self.read_pos()?),
+          color: self.read_paint()?,
+        },
+        TAG_POLYLINE => {
+          let len = self.read_varint()?;
+          let points = (0..len).map(|_| self.read_pos()).collect::<io::Result<_>>()?;
+          UserEvent::Polyline {
+            points,
+            color: self.read_paint()?,
+          }
+        }
+        TAG_CLEAR => UserEvent::Clear(self.read_paint()?),
+        TAG_PALETTE => {
+          let len = self.read_varint()?;
+          UserEvent::Palette((0..len).map(|_| self.read_color()).collect::<io::Result<_>>()?)
+        }
+        TAG_STATUS => {
+          let mut status = vec![0; self.read_varint()? as usize];
+          self.input.read_exact(&mut status)?;
+          UserEvent::Status(String::from_utf8(status).map_err(|_| invalid_data("invalid status"))?)
+        }
+        TAG_SLEEP => UserEvent::Sleep(Duration::from_nanos(self.read_varint()?)),
+        tag => return Err(invalid_data(format!("unknown event tag {}", tag))),
+      };
+      return Ok(Some(event));
+    }
+  }
+
+  fn read_byte(&mut self) -> io::Result<u8> {
+    let mut byte = [0u8];
+    self.input.read_exact(&mut byte)?;
+    Ok(byte[0])
+  }
+
+  fn read_varint(&mut self) -> io::Result<u64> {
+    let mut value = 0u64;
+    for shift in (0..64).step_by(7) {
+      let byte = self.read_byte()?;
+      value |= u64::from(byte & 0x7f) << shift;
+      if byte & 0x80 == 0 {
+        return Ok(value);
+      }
+    }
+    Err(invalid_data("varint is too long"))
+  }
+
+  fn read_pos(&mut self) -> io::Result<Pos2> {
+    let unzigzag = |v: u64| ((v >> 1) as isize) ^ -((v & 1) as isize);
+    let x = unzigzag(self.read_varint()?);
+    let y = unzigzag(self.read_varint()?);
+    Ok(Pos2::new(x, y))
+  }
+
+  fn read_color(&mut self) -> io::Result<Color> {
+    let mut rgb = [0u8; 3];
+    self.input.read_exact(&mut rgb)?;
+    Ok((rgb[0], rgb[1], rgb[2]))
+  }
+
+  fn read_paint(&mut self) -> io::Result<Paint> {
+    match self.read_byte()? {
+      PAINT_RGB => Ok(Paint::Rgb(self.read_color()?)),
+      PAINT_INDEX => Ok(Paint::Index(self.read_byte()?)),
+      kind => Err(invalid_data(format!("unknown paint {}", kind))),
+    }
+  }
+}
+
+struct LogWriter<W: Write> {
+  out: W,
+  last_pos: Pos2,
+  colors: HashMap<Color, u8>,
+}
+
+impl<W: Write> LogWriter<W> {
+  fn new(mut out: W) -> io::Result<LogWriter<W>> {
+    out.write_all(MAGIC)?;
+    Ok(LogWriter {
+      out,
+      last_pos: Pos2::zero(),
+      colors: HashMap::new(),
+    })
+  }
+
+  fn write(&mut self, event: &UserEvent) -> io::Result<()> {
+    match event {
+      UserEvent::ResizeAndDraw {
+        top_left,
+        bottom_right,
+        framebuf,
+      } => {
+        self.out.write_all(&[TAG_DRAW])?;
+        self.write_pos(*top_left)?;
+        self.write_pos(*bottom_right)?;
+        let rgb = framebuf.chunks(4).flat_map(|c| [c[0], c[1], c[2]]).collect::<Vec<_>>();
+        self.out.write_all(&rgb)
+      }
+      UserEvent::Pixel { pos, color } => {
+        let rgb = match *color {
+          Paint::Rgb(rgb) => rgb,
+          Paint::Index(idx) => {
+            self.out.write_all(&[TAG_PIXEL_INDEX])?;
+            self.write_pos(*pos - self.last_pos)?;
+            self.last_pos = *pos;
+            return self.out.write_all(&[idx]);
+          }
+        };
+        if !self.colors.contains_key(&rgb) && self.colors.len() < COLOR_TABLE_SIZE {
+          self.out.write_all(&[TAG_COLOR])?;
+          self.write_color(rgb)?;
+          self.colors.insert(rgb, self.colors.len() as u8);
+        }
+        let idx = self.colors.get(&rgb).copied();
+        self
+          .out
+          .write_all(&[if idx.is_some() { TAG_PIXEL } else { TAG_PIXEL_RGB }])?;
+        self.write_pos(*pos - self.last_pos)?;
+        self.last_pos = *pos;
+        match idx {
+          Some(idx) => self.out.write_all(&[idx]),
+          None => self.write_color(rgb),
+        }
+      }
+      UserEvent::FillRect { area, color } => {
+        self.out.write_all(&[TAG_FILL_RECT])?;
+        self.write_pos(area.top_left)?;
+        self.write_pos(area.bottom_right)?;
+        self.write_paint(*color)
+      }
+      UserEvent::Polyline { points, color } => {
+        self.out.write_all(&[TAG_POLYLINE])?;
+        self.write_varint(points.len() as u64)?;
+        for &point in points {
+          self.write_pos(point)?;
+        }
+        self.write_paint(*color)
+      }
+      UserEvent::Clear(color) => {
+        self.out.write_all(&[TAG_CLEAR])?;
+        self.write_paint(*color)
+      }
+      UserEvent::Palette(palette) => {
+        self.out.write_all(&[TAG_PALETTE])?;
+        self.write_varint(palette.len() as u64)?;
+        for &color in palette {
+          self.write_color(color)?;
+        }
+        Ok(())
+      }
+      UserEvent::Status(status) => {
+        self.out.write_all(&[TAG_STATUS])?;
+        self.write_varint(status.len() as u64)?;
+        self.out.write_all(status.as_bytes())
+      }
+      UserEvent::Sleep(dur) => {
+        self.out.write_all(&[TAG_SLEEP])?;
+        self.write_varint(dur.as_nanos() as u64)
+      }
+    }
+  }
+
+  fn write_varint(&mut self, mut value: u64) -> io::Result<()> {
+    while value >= 0x80 {
+      self.out.write_all(&[(value as u8) | 0x80])?;
+      value >>= 7;
+    }
+    self.out.write_all(&[value as u8])
+  }
+
+  fn write_pos(&mut self, pos: Pos2) -> io::Result<()> {
+    let zigzag = |v: isize| ((v << 1) ^ (v >> (isize::BITS - 1))) as u64;
+    self.write_varint(zigzag(pos.x))?;
+    self.write_varint(zigzag(pos.y))
+  }
+
+  fn write_color(&mut self, (r, g, b): Color) -> io::Result<()> {
+    self.out.write_all(&[r, g, b])
+  }
+
+  fn write_paint(&mut self, paint: Paint) -> io::Result<()> {
+    match paint {
+      Paint::Rgb(color) => {
+        self.out.write_all(&[PAINT_RGB])?;
+        self.write_color(color)
+      }
+      Paint::Index(idx) => self.out.write_all(&[PAINT_INDEX, idx]),
+    }
+  }
+}
+
+fn invalid_data(message: impl Into<String>) -> io::Error {
+  io::Error::new(io::ErrorKind::InvalidData, message.into())
+}
@@ -0,0 +1,506 @@
+use crate::{Area, Dir2, Grid, Poly, Pos2};
+use std::cell::Cell;
+use std::io;
+use std::sync::mpsc::{channel, Sender};
+use std::sync::{Arc, Condvar, Mutex};
+use std::time::Duration;
+
+pub use log::*;
+pub use record::*;
+pub use terminal::*;
+pub use window::*;
+
+mod font;
+mod log;
+mod record;
+mod terminal;
+mod window;
+
+pub struct Channel {
+  channel: Option<Sender<UserEvent>>,
+  /// Whether `sleep` actually sleeps. Backends which do not show the progress live receive the
+  /// sleeps as events instead.
+  realtime: bool,
+  /// Playback control of an interactive backend.
+  control: Option<Arc<Control>>,
+  /// Whether the worker has called `sleep` yet. Workers which never sleep are paused at every
+  /// drawing event instead.
+  slept: Cell<bool>,
+}
+
+pub type Color = (u8, u8, u8);
+
+impl Channel {
+  pub fn empty() -> Channel {
+    Channel {
+      channel: None,
+      realtime: false,
+      control: None,
+      slept: Cell::new(false),
+    }
+  }
+
+  fn new(sender: Sender<UserEvent>, realtime: bool) -> Channel {
+    Channel {
+      channel: Some(sender),
+      realtime,
+      control: None,
+      slept: Cell::new(false),
+    }
+  }
+
+  fn with_control(mut self, control: Arc<Control>) -> Channel {
+    self.control = Some(control);
+    self
+  }
+
+  fn send(&self, event: UserEvent) {
+    if let Some(ref channel) = self.channel {
+      if let Some(ref control) = self.control {
+        if !self.slept.get() {
+          control.gate();
+        }
+      }
+      channel.send(event).map_err(|_| ()).expect("message failed");
+    }
+  }
+
+  pub fn draw_init(&self, top_left: Pos2, bottom_right: Pos2, color_fn: impl Fn(Pos2) -> Color) {
+    if self.channel.is_none() {
+      return;
+    }
+    let dims = bottom_right - top_left + Dir2::new(1, 1);
+    let mut framebuf: Vec<u8> = vec![0u8; (dims.x * dims.y * 4) as usize];
+    for pos in Pos2::iter_rect(top_left, bottom_right) {
+      let (r, g, b) = color_fn(pos);
+      let idx = (((pos.y - top_left.y) * dims.x + (pos.x - top_left.x)) as usize) * 4;
+      framebuf[idx..idx + 4].copy_from_slice(&[r, g, b, 0xff]);
+    }
+    self.send(UserEvent::ResizeAndDraw {
+      top_left,
+      bottom_right,
+      framebuf,
+    });
+  }
+
+  /// Initialize the drawing with the grid, coloring every cell.
+  pub fn draw_grid<T: Clone>(&self, grid: &Grid<T>, color_fn: impl Fn(&T) -> Color) {
+    self.draw_init(grid.top_left(), grid.bottom_right(), |pos| color_fn(&grid[pos]));
+  }
+
+  pub fn draw_map_pixel(&self, pos: Pos2, color: impl Into<Paint>) {
+    self.send(UserEvent::Pixel {
+      pos,
+      color: color.into(),
+    });
+  }
+
+  /// Fill the area, both corners inclusive.
+  pub fn fill_rect(&self, area: Area, color: impl Into<Paint>) {
+    self.send(UserEvent::FillRect {
+      area,
+      color: color.into(),
+    });
+  }
+
+  /// Draw a straight line of any slope, both ends inclusive.
+  pub fn draw_line(&self, from: Pos2, to: Pos2, color: impl Into<Paint>) {
+    self.send(UserEvent::Polyline {
+      points: vec![from, to],
+      color: color.into(),
+    });
+  }
+
+  /// Draw lines connecting the points one after another.
+  pub fn draw_polyline(&self, points: &[Pos2], color: impl Into<Paint>) {
+    self.send(UserEvent::Polyline {
+      points: points.to_vec(),
+      color: color.into(),
+    });
+  }
+
+  /// Draw the outline of the polygon, including the edge from the last point back to the first.
+  pub fn draw_poly(&self, poly: &Poly, color: impl Into<Paint>) {
+    if self.channel.is_none() || poly.points.is_empty() {
+      return;
+    }
+    let mut points = poly.points.clone();
+    points.push(poly.points[0]);
+    self.send(UserEvent::Polyline {
+      points,
+      color: color.into(),
+    });
+  }
+
+  /// Fill the whole frame with a single color.
+  pub fn clear(&self, color: impl Into<Paint>) {
+    self.send(UserEvent::Clear(color.into()));
+  }
+
+  /// Set the colors `Paint::Index` refers to. Changing the palette does not affect what has
+  /// already been drawn.
+  pub fn set_palette(&self, palette: &[Color]) {
+    self.send(UserEvent::Palette(palette.to_vec()));
+  }
+
+  /// Show a line of text with the drawing (for example, the iteration count or the current cost).
+  pub fn set_status(&self, status: impl Into<String>) {
+    if self.channel.is_some() {
+      self.send(UserEvent::Status(status.into()));
+    }
+  }
+
+  /// Pause the worker, so the progress can be seen. Interactive backends can pause the worker
+  /// here or change the speed, which scales the duration.
+  pub fn sleep(&self, dur: Duration) {
+    if self.realtime {
+      self.slept.set(true);
+      match self.control {
+        Some(ref control) => {
+          control.gate();
+          std::thread::sleep(dur.div_f64(control.speed()));
+        }
+        None => std::thread::sleep(dur),
+      }
+    } else {
+      self.send(UserEvent::Sleep(dur));
+    }
+  }
+}
+
+/// Run the worker on its own thread for a headless backend, passing every event it sends to the
+/// handler, and wait for the worker to finish. Panics of the worker are passed on. Returns the
+/// first error of the handler, which is not called anymore after failing.
+fn run_headless(
+  worker_fn: impl FnOnce(Channel) + Send + 'static,
+  mut event_fn: impl FnMut(UserEvent) -> io::Result<()>,
+) -> io::Result<()> {
+  let (sender, receiver) = channel();
+  let channel = Channel::new(sender, false);
+  let worker = std::thread::spawn(|| worker_fn(channel));
+
+  let mut result = Ok(());
+  // Keep receiving even if handling has failed, so the worker is not killed mid-way.
+  for event in receiver {
+    if result.is_ok() {
+      result = event_fn(event);
+    }
+  }
+  if let Err(panic) = worker.join() {
+    std::panic::resume_unwind(panic);
+  }
+  result
+}
+
+/// Playback state shared between an interactive backend and the worker.
+struct Control {
+  state: Mutex<ControlState>,
+  wakeup: Condvar,
+}
+
+struct ControlState {
+  paused: bool,
+  /// Amount of steps the worker can take while paused.
+  steps: usize,
+  speed: f64,
+}
+
+impl Control {
+  fn new() -> Control {
+    Control {
+      state: Mutex::new(ControlState {
+        paused: false,
+        steps: 0,
+        speed: 1.0,
+      }),
+      wakeup: Condvar::new(),
+    }
+  }
+
+  /// Block the worker while paused, unless it is allowed to take a step.
+  fn gate(&self) {
+    let mut state = self.state.lock().unwrap();
+    while state.paused && state.steps == 0 {
+      state = self.wakeup.wait(state).unwrap();
+    }
+    if state.paused {
+      state.steps -= 1;
+    }
+  }
+
+  fn update(&self, update_fn: impl FnOnce(&mut ControlState)) {
+    update_fn(&mut self.state.lock().unwrap());
+    self.wakeup.notify_all();
+  }
+
+  fn is_paused(&self) -> bool {
+    self.state.lock().unwrap().paused
+  }
+
+  fn speed(&self) -> f64 {
+    self.state.lock().unwrap().speed
+  }
+}
+
+/// Color to draw with, either given directly or as an index into the palette.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Paint {
+  Rgb(Color),
+  /// Colors not set in the palette are black.
+  Index(u8),
+}
+
+impl From<Color> for Paint {
+  fn from(color: Color) -> Self {
+    Paint::Rgb(color)
+  }
+}
+
+impl From<u8> for Paint {
+  fn from(idx: u8) -> Self {
+    Paint::Index(idx)
+  }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum UserEvent {
+  ResizeAndDraw {
+    top_left: Pos2,
+    bottom_right: Pos2,
+    framebuf: Vec<u8>,
+  },
+  Pixel {
+    pos: Pos2,
+    color: Paint,
+  },
+  FillRect {
+    area: Area,
+    color: Paint,
+  },
+  /// Lines are drawn between the consecutive points.
+  Polyline {
+    points: Vec<Pos2>,
+    color: Paint,
+  },
+  Clear(Paint),
+  Palette(Vec<Color>),
+  Status(String),
+  /// Only sent to the backends which are not realtime.
+  Sleep(Duration),
+}
+
+/// Part of the frame changed by an event.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Change {
+  None,
+  /// Pixels in the area (within the frame) might have changed.
+  Pixels(Area),
+  Status,
+}
+
+/// Frame buffer the drawing events are applied to, RGBA with a single pixel per map position.
+#[derive(Clone, Debug, Default)]
+pub struct Frame {
+  top_left: Pos2,
+  bottom_right: Pos2,
+  framebuf: Vec<u8>,
+  palette: Vec<Color>,
+  status: String,
+}
+
+impl Frame {
+  pub fn top_left(&self) -> Pos2 {
+    self.top_left
+  }
+
+  pub fn bottom_right(&self) -> Pos2 {
+    self.bottom_right
+  }
+
+  /// Width and height in pixels, zero if nothing was drawn yet.
+  pub fn dims(&self) -> (usize, usize) {
+    if self.framebuf.is_empty() {
+      (0, 0)
+    } else {
+      let dims = self.bottom_right - self.top_left + Dir2::new(1, 1);
+      (dims.x as usize, dims.y as usize)
+    }
+  }
+
+  /// Pixels in RGBA format, row by row.
+  pub fn rgba(&self) -> &[u8] {
+    &self.framebuf
+  }
+
+  /// Status text set by the worker, empty if none.
+  pub fn status(&self) -> &str {
+    &self.status
+  }
+
+  fn index(&self, pos: Pos2) -> Option<usize> {
+    if self.framebuf.is_empty() || !pos.inside_rect(self.top_left, self.bottom_right) {
+      return None;
+    }
+    let relative = pos - self.top_left;
+    let w = self.bottom_right.x - self.top_left.x + 1;
+    Some(((relative.y * w + relative.x) as usize) * 4)
+  }
+
+  pub fn get(&self, pos: Pos2) -> Option<Color> {
+    self
+      .index(pos)
+      .map(|idx| (self.framebuf[idx], self.framebuf[idx + 1], self.framebuf[idx + 2]))
+  }
+
+  fn resolve(&self, paint: Paint) -> Color {
+    match paint {
+      Paint::Rgb(color) => color,
+      Paint::Index(idx) => self.palette.get(usize::from(idx)).copied().unwrap_or((0, 0, 0)),
+    }
+  }
+
+  fn set(&mut self, pos: Pos2, (r, g, b): Color) {
+    if let Some(idx) = self.index(pos) {
+      self.framebuf[idx..idx + 4].copy_from_slice(&[r, g, b, 0xff]);
+    }
+  }
+
+  /// The area clipped to the frame, `None` if they do not overlap.
+  fn clip(&self, area: Area) -> Option<Area> {
+    if self.framebuf.is_empty() {
+      return None;
+    }
+    let clipped = Area::new(
+      Pos2::new(
+        area.top_left.x.max(self.top_left.x),
+        area.top_left.y.max(self.top_left.y),
+      ),
+      Pos2::new(
+        area.bottom_right.x.min(self.bottom_right.x),
+        area.bottom_right.y.min(self.bottom_right.y),
+      ),
+    );
+    let dims = clipped.bottom_right - clipped.top_left;
+    (dims.x >= 0 && dims.y >= 0).then_some(clipped)
+  }
+
+  fn changed(&self, area: Area) -> Change {
+    self.clip(area).map_or(Change::None, Change::Pixels)
+  }
+
+  /// Events drawing the whole frame at once, together with the palette and the status.
+  fn snapshot(&self) -> Vec<UserEvent> {
+    let mut events = Vec::new();
+    if !self.framebuf.is_empty() {
+      events.push(UserEvent::ResizeAndDraw {
+        top_left: self.top_left,
+        bottom_right: self.bottom_right,
+        framebuf: self.framebuf.clone(),
+      });
+    }
+    if !self.palette.is_empty() {
+      events.push(UserEvent::Palette(self.palette.clone()));
+    }
+    if !self.status.is_empty() {
+      events.push(UserEvent::Status(self.status.clone()));
+    }
+    events
+  }
+
+  /// Apply the event to the frame, returning what has changed.
+  fn apply(&mut self, event: UserEvent) -> Change {
+    match event {
+      UserEvent::ResizeAndDraw {
+        top_left,
+        bottom_right,
+        framebuf,
+      } => {
+        self.top_left = top_left;
+        self.bottom_right = bottom_right;
+        self.framebuf = framebuf;
+        self.changed(Area::new(top_left, bottom_right))
+      }
+      UserEvent::Pixel { pos, color } => {
+        self.set(pos, self.resolve(color));
+        self.changed(Area::new(pos, pos))
+      }
+      UserEvent::FillRect { area, color } => {
+        let color = self.resolve(color);
+        let Some(clipped) = self.clip(area) else {
+          return Change::None;
+        };
+        for pos in Pos2::iter_rect(clipped.top_left, clipped.bottom_right) {
+          self.set(pos, color);
+        }
+        Change::Pixels(clipped)
+      }
+      UserEvent::Polyline { points, color } => {
+        let color = self.resolve(color);
+        for pair in points.windows(2) {
+          for pos in line(pair[0], pair[1]) {
+            self.set(pos, color);
+          }
+        }
+        if points.len() == 1 {
+          self.set(points[0], color);
+        }
+        let Some(&first) = points.first() else {
+          return Change::None;
+        };
+        let bounds = points.iter().fold(Area::new(first, first), |area, &p| {
+          Area::new(
+            Pos2::new(area.top_left.x.min(p.x), area.top_left.y.min(p.y)),
+            Pos2::new(area.bottom_right.x.max(p.x), area.bottom_right.y.max(p.y)),
+          )
+        });
+        self.changed(bounds)
+      }
+      UserEvent::Clear(color) => {
+        let (r, g, b) = self.resolve(color);
+        for pixel in self.framebuf.chunks_mut(4) {
+          pixel.copy_from_slice(&[r, g, b, 0xff]);
+        }
+        self.changed(Area::new(self.top_left, self.bottom_right))
+      }
+      UserEvent::Palette(palette) => {
+        self.palette = palette;
+        Change::None
+      }
+      UserEvent::Status(status) => {
+        if self.status == status {
+          return Change::None;
+        }
+        self.status = status;
+        Change::Status
+      }
+      UserEvent::Sleep(_) => Change::None,
+    }
+  }
+}
+
+/// Positions of the line between the two points (both inclusive), using Bresenham's algorithm.
+fn line(from: Pos2, to: Pos2) -> impl Iterator<Item = Pos2> {
+  let (dx, dy) = ((to.x - from.x).abs(), -(to.y - from.y).abs());
+  let step = Dir2::new((to.x - from.x).signum(), (to.y - from.y).signum());
+  let mut pos = Some(from);
+  let mut err = dx + dy;
+  std::iter::from_fn(move || {
+    let current = pos?;
+    pos = if current == to {
+      None
+    } else {
+      let mut next = current;
+      let err2 = 2 * err;
+      if err2 >= dy {
+        err += dy;
+        next.x += step.dx;
+      }
+      if err2 <= dx {
+        err += dx;
+        next.y += step.dy;
+      }
+      Some(next)
+    };
+    Some(current)
+  })
+}
@@ -0,0 +1,246 @@
+use super::font::draw_text;
+use super::{run_headless, Change, Channel, Frame, UserEvent};
+use std::fs::File;
+use std::io::{self, BufWriter};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// Where and how the recorded frames are written.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RecordFormat {
+  /// Numbered PNG files (`frame00000.png`, `frame00001.png`, ...) in the directory.
+  PngSequence(PathBuf),
+  /// Single animated GIF file.
+  Gif(PathBuf),
+}
+
+impl RecordFormat {
+  /// Animated GIF if the path has the `gif` extension, PNG sequence in the directory otherwise.
+  pub fn from_path(path: impl Into<PathBuf>) -> RecordFormat {
+    let path = path.into();
+    if path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("gif")) {
+      RecordFormat::Gif(path)
+    } else {
+      RecordFormat::PngSequence(path)
+    }
+  }
+}
+
+/// When the recorder takes a frame.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FrameTrigger {
+  /// Every n-th call of `Channel::sleep`.
+  Sleeps(usize),
+  /// Every n drawing events.
+  Events(usize),
+}
+
+/// Headless backend writing what the worker draws into image files.
+#[derive(Clone, Debug)]
+pub struct Recorder {
+  format: RecordFormat,
+  trigger: FrameTrigger,
+  scale: usize,
+  delay: Option<Duration>,
+}
+
+impl Recorder {
+  /// Recorder taking a frame on every sleep, one image pixel per map position.
+  pub fn new(format: RecordFormat) -> Recorder {
+    Recorder {
+      format,
+      trigger: FrameTrigger::Sleeps(1),
+      scale: 1,
+      delay: None,
+    }
+  }
+
+  pub fn with_trigger(mut self, trigger: FrameTrigger) -> Recorder {
+    let (FrameTrigger::Sleeps(n) | FrameTrigger::Events(n)) = trigger;
+    assert!(n > 0, "frame trigger must be positive");
+    self.trigger = trigger;
+    self
+  }
+
+  /// Size of a single map position in the images, in pixels.
+  pub fn with_scale(mut self, scale: usize) -> Recorder {
+    assert!(scale > 0, "scale must be positive");
+    self.scale = scale;
+    self
+  }
+
+  /// Fixed delay between the frames of an animated GIF. By default, the frame is shown for as long
+  /// as the worker has slept since the previous one.
+  pub fn with_delay(mut self, delay: Duration) -> Recorder {
+    self.delay = Some(delay);
+    self
+  }
+
+  /// Run the worker, writing a frame whenever the trigger fires, and the final state once the
+  /// worker is done. Frames without any change since the previous one are skipped. Returns the
+  /// amount of frames written.
+  pub fn record(&self, worker_fn: impl FnOnce(Channel) + Send + 'static) -> io::Result<usize> {
+    let mut sink = Sink::new(&self.format)?;
+    let mut frame = Frame::default();
+    let mut dirty = false;
+    let mut pending = 0;
+    let mut slept = Duration::ZERO;
+    run_headless(worker_fn, |event| {
+      let is_sleep = if let UserEvent::Sleep(dur) = event {
+        slept += dur;
+        true
+      } else {
+        false
+      };
+      dirty |= frame.apply(event) != Change::None;
+      let fire = match self.trigger {
+        FrameTrigger::Sleeps(n) if is_sleep => {
+          pending += 1;
+          pending >= n
+        }
+        FrameTrigger::Events(n) if !is_sleep => {
+          pending += 1;
+          pending >= n
+        }
+        _ => false,
+      };
+      if fire {
+        pending = 0;
+        // Nothing to write until the worker draws the first frame, even if the status is set
+        if dirty && frame.dims() != (0, 0) {
+          dirty = false;
+          let dur = std::mem::replace(&mut slept, Duration::ZERO);
+          return sink.write(self, &frame, dur);
+        }
+      }
+      Ok(())
+    })?;
+    if dirty && frame.dims() != (0, 0) {
+      sink.write(self, &frame, slept)?;
+    }
+    sink.finish()
+  }
+}
+
+/// Scale the frame up, returning the RGBA pixels and the dimensions of the image. The status is
+/// drawn over the top left corner.
+fn scaled(frame: &Frame, scale: usize) -> (Vec<u8>, usize, usize) {
+  let (width, height) = frame.dims();
+  let rgba = frame.rgba();
+  let mut data = Vec::with_capacity(width * height * scale * scale * 4);
+  for row in rgba.chunks(width * 4) {
+    let start = data.len();
+    for pixel in row.chunks(4) {
+      for _ in 0..scale {
+        data.extend_from_slice(pixel);
+      }
+    }
+    for _ in 1..scale {
+      data.extend_from_within(start..start + width * scale * 4);
+    }
+  }
+  if !frame.status().is_empty() {
+    draw_text(&mut data, width * scale, frame.status(), scale.div_ceil(2));
+  }
+  (data, width * scale, height * scale)
+}
+
+/// Copy the image into an image of the given size, cropping or padding it with transparent pixels.
+fn fit(data: &[u8], width: usize, height: usize, to_width: usize, to_height: usize) -> Vec<u8> {
+  let mut result = vec![0; to_width * to_height * 4];
+  let copy = width.min(to_width) * 4;
+  for y in 0..height.min(to_height) {
+    result[y * to_width * 4..][..copy].copy_from_slice(&data[y * width * 4..][..copy]);
+  }
+  result
+}
+
+enum Sink {
+  Png {
+    dir: PathBuf,
+    count: usize,
+  },
+  /// Encoder is created once the first frame gives the size of the animation.
+  Gif {
+    file: Option<BufWriter<File>>,
+    encoder: Option<(gif::Encoder<BufWriter<File>>, usize, usize)>,
+    count: usize,
+  },
+}
+
+impl Sink {
+  fn new(format: &RecordFormat) -> io::Result<Sink> {
+    Ok(match format {
+      RecordFormat::PngSequence(dir) => {
+        std::fs::create_dir_all(dir)?;
+        Sink::Png {
+          dir: dir.clone(),
+          count: 0,
+        }
+      }
+      RecordFormat::Gif(path) => Sink::Gif {
+        file: Some(BufWriter::new(File::create(path)?)),
+        encoder: None,
+        count: 0,
+      },
+    })
+  }
+
+  fn write(&mut self, recorder: &Recorder, frame: &Frame, slept: Duration) -> io::Result<()> {
+    let (data, width, height) = scaled(frame, recorder.scale);
+    match self {
+      Sink::Png { dir, count } => {
+        write_png(&dir.join(format!("frame{:05}.png", count)), &data, width, height)?;
+        *count += 1;
+      }
+      Sink::Gif { file, encoder, count } => {
+        if encoder.is_none() {
+          let (w, h) = gif_dims(width, height)?;
+          let mut gif = gif::Encoder::new(file.take().unwrap(), w, h, &[]).map_err(io::Error::other)?;
+          gif.set_repeat(gif::Repeat::Infinite).map_err(io::Error::other)?;
+          *encoder = Some((gif, width, height));
+        }
+        let (gif, gif_width, gif_height) = encoder.as_mut().unwrap();
+        let mut data = fit(&data, width, height, *gif_width, *gif_height);
+        let mut gif_frame = gif::Frame::from_rgba_speed(*gif_width as u16, *gif_height as u16, &mut data, 10);
+        // Viewers show frames with shorter delays for much longer, so two centiseconds is the minimum
+        let delay = recorder.delay.unwrap_or(slept).as_millis() / 10;
+        gif_frame.delay = delay.clamp(2, u16::MAX.into()) as u16;
+        gif.write_frame(&gif_frame).map_err(io::Error::other)?;
+        *count += 1;
+      }
+    }
+    Ok(())
+  }
+
+  fn finish(self) -> io::Result<usize> {
+    match self {
+      Sink::Png { count, .. } => Ok(count),
+      Sink::Gif { encoder, count, .. } => {
+        if let Some((gif, _, _)) = encoder {
+          gif.into_inner()?.into_inner().map_err(|err| err.into_error())?;
+        }
+        Ok(count)
+      }
+    }
+  }
+}
+
+fn gif_dims(width: usize, height: usize) -> io::Result<(u16, u16)> {
+  match (u16::try_from(width), u16::try_from(height)) {
+    (Ok(w), Ok(h)) => Ok((w, h)),
+    _ => Err(io::Error::new(
+      io::ErrorKind::InvalidInput,
+      format!("frame {}x{} is too large for GIF", width, height),
+    )),
+  }
+}
+
+fn write_png(path: &Path, data: &[u8], width: usize, height: usize) -> io::Result<()> {
+  let mut encoder = png::Encoder::new(BufWriter::new(File::create(path)?), width as u32, height as u32);
+  encoder.set_color(png::ColorType::Rgba);
+  encoder.set_depth(png::BitDepth::Eight);
+  let mut writer = encoder.write_header().map_err(io::Error::other)?;
+  writer.write_image_data(data).map_err(io::Error::other)?;
+  writer.finish().map_err(io::Error::other)
+}
@@ -0,0 +1,195 @@
+use super::{Change, Channel, Color, Frame, UserEvent};
+use crate::{Dir2, Pos2};
+use std::io::{self, Write};
+use std::sync::mpsc::{channel, RecvTimeoutError};
+use std::time::{Duration, Instant};
+
+/// How often the terminal is updated.
+const FRAME_INTERVAL: Duration = Duration::from_millis(1000 / 30);
+
+/// Terminal size used when it cannot be detected (for example, when the output is redirected).
+const DEFAULT_SIZE: (usize, usize) = (80, 24);
+
+/// Run the worker, drawing everything it draws in the terminal with 24-bit ANSI colors. Every
+/// character cell shows two pixels on top of each other, maps larger than the terminal are scaled
+/// down. The status is shown on the line below the map. The terminal is restored once the worker
+/// is done, so whatever the worker returns can be printed afterwards.
+pub fn visualize_terminal<T: Send + 'static>(worker_fn: impl FnOnce(Channel) -> T + Send + 'static) -> io::Result<T> {
+  let (sender, receiver) = channel();
+  let channel = Channel::new(sender, true);
+  let worker = std::thread::spawn(|| worker_fn(channel));
+
+  let stdout = io::stdout();
+  let mut out = io::BufWriter::new(stdout.lock());
+  let mut screen = Screen::new(terminal_dims());
+  let mut result = write!(out, "\x1b[?25l\x1b[2J");
+  let mut last_flush = Instant::now();
+  loop {
+    match receiver.recv_timeout(FRAME_INTERVAL) {
+      Ok(event) => screen.apply(event),
+      Err(RecvTimeoutError::Timeout) => {}
+      Err(RecvTimeoutError::Disconnected) => break,
+    }
+    if last_flush.elapsed() >= FRAME_INTERVAL && result.is_ok() {
+      result = screen.flush(&mut out);
+      last_flush = Instant::now();
+    }
+  }
+  if result.is_ok() {
+    result = screen.flush(&mut out);
+  }
+  // Restore the colors and the cursor, leaving it below the map and the status.
+  let restored = write!(out, "\x1b[0m\x1b[{};1H\x1b[?25h", screen.rows + 2).and_then(|_| out.flush());
+  let value = match worker.join() {
+    Ok(value) => value,
+    Err(panic) => std::panic::resume_unwind(panic),
+  };
+  result.and(restored).map(|_| value)
+}
+
+/// Size of the terminal, in character cells.
+fn terminal_dims() -> (usize, usize) {
+  match terminal_size::terminal_size() {
+    Some((terminal_size::Width(w), terminal_size::Height(h))) => (w.into(), h.into()),
+    None => DEFAULT_SIZE,
+  }
+}
+
+/// Character cells currently drawn in the terminal, with the top and bottom pixel colors.
+struct Screen {
+  frame: Frame,
+  terminal: (usize, usize),
+  /// Amount of map positions in each direction shown as a single pixel.
+  scale: usize,
+  cols: usize,
+  rows: usize,
+  cells: Vec<(Color, Color)>,
+  /// Cells changed since the last flush.
+  dirty: Vec<usize>,
+  is_dirty: Vec<bool>,
+  status_dirty: bool,
+}
+
+impl Screen {
+  fn new(terminal: (usize, usize)) -> Screen {
+    Screen {
+      frame: Frame::default(),
+      terminal,
+      scale: 1,
+      cols: 0,
+      rows: 0,
+      cells: Vec::new(),
+      dirty: Vec::new(),
+      is_dirty: Vec::new(),
+      status_dirty: false,
+    }
+  }
+
+  fn apply(&mut self, event: UserEvent) {
+    let resize = matches!(event, UserEvent::ResizeAndDraw { .. });
+    match self.frame.apply(event) {
+      _ if resize => self.resize(),
+      Change::None => {}
+      Change::Pixels(area) => {
+        let top_left = area.top_left - self.frame.top_left();
+        let bottom_right = area.bottom_right - self.frame.top_left();
+        for row in top_left.y as usize / self.scale / 2..=bottom_right.y as usize / self.scale / 2 {
+          for col in top_left.x as usize / self.scale..=bottom_right.x as usize / self.scale {
+            self.update_cell(col, row);
+          }
+        }
+      }
+      Change::Status => self.status_dirty = true,
+    }
+  }
+
+  /// Pick the scale so the whole frame fits the terminal (keeping the last lines for the status and
+  /// the cursor) and mark every cell for drawing.
+  fn resize(&mut self) {
+    let (width, height) = self.frame.dims();
+    let (cols, rows) = self.terminal;
+    let rows = rows.saturating_sub(2).max(1);
+    self.scale = width.div_ceil(cols).max(height.div_ceil(rows * 2)).max(1);
+    self.cols = width.div_ceil(self.scale);
+    self.rows = height.div_ceil(self.scale * 2);
+    self.cells = vec![((0, 0, 0), (0, 0, 0)); self.cols * self.rows];
+    self.is_dirty = vec![false; self.cells.len()];
+    self.dirty.clear();
+    self.status_dirty = true;
+    for row in 0..self.rows {
+      for col in 0..self.cols {
+        self.update_cell(col, row);
+        self.mark_dirty(row * self.cols + col);
+      }
+    }
+  }
+
+  fn update_cell(&mut self, col: usize, row: usize) {
+    let cell = (self.pixel(col, row * 2), self.pixel(col, row * 2 + 1));
+    let idx = row * self.cols + col;
+    if self.cells[idx] != cell {
+      self.cells[idx] = cell;
+      self.mark_dirty(idx);
+    }
+  }
+
+  fn mark_dirty(&mut self, idx: usize) {
+    if !self.is_dirty[idx] {
+      self.is_dirty[idx] = true;
+      self.dirty.push(idx);
+    }
+  }
+
+  /// Average color of the map positions shown as the given pixel.
+  fn pixel(&self, x: usize, y: usize) -> Color {
+    let top_left = self.frame.top_left() + Dir2::new((x * self.scale) as isize, (y * self.scale) as isize);
+    let bottom_right = top_left + Dir2::new(self.scale as isize - 1, self.scale as isize - 1);
+    let (mut r, mut g, mut b, mut count) = (0usize, 0usize, 0usize, 0usize);
+    for pos in Pos2::iter_rect(top_left, bottom_right) {
+      if let Some(color) = self.frame.get(pos) {
+        r += usize::from(color.0);
+        g += usize::from(color.1);
+        b += usize::from(color.2);
+        count += 1;
+      }
+    }
+    if count == 0 {
+      return (0, 0, 0);
+    }
+    ((r / count) as u8, (g / count) as u8, (b / count) as u8)
+  }
+
+  /// Draw the changed cells, moving the cursor only when they are not next to each other.
+  fn flush(&mut self, out: &mut impl Write) -> io::Result<()> {
+    if self.status_dirty {
+      let status = self.frame.status().chars().take(self.terminal.0).collect::<String>();
+      write!(out, "\x1b[0m\x1b[{};1H{}\x1b[K", self.rows + 1, status)?;
+      self.status_dirty = false;
+    }
+    if self.dirty.is_empty() {
+      return out.flush();
+    }
+    self.dirty.sort_unstable();
+    let mut cursor = None;
+    for &idx in &self.dirty {
+      self.is_dirty[idx] = false;
+      if cursor != Some(idx) {
+        write!(out, "\x1b[{};{}H", idx / self.cols + 1, idx % self.cols + 1)?;
+      }
+      let ((tr, tg, tb), (br, bg, bb)) = self.cells[idx];
+      write!(
+        out,
+        "\x1b[38;2;{};{};{}m\x1b[48;2;{};{};{}m\u{2580}",
+        tr, tg, tb, br, bg, bb
+      )?;
+      // Cursor stays at the end of the line after the last column
+      cursor = if (idx + 1) % self.cols == 0 {
+        None
+      } else {
+        Some(idx + 1)
+      };
+    }
+    self.dirty.clear();
+    out.flush()
+  }
+}
@@ -0,0 +1,241 @@
+use super::font::draw_text;
+use super::{Change, Channel, Control, Frame};
+use crate::Pos2;
+use pixels::{Pixels, SurfaceTexture};
+use std::sync::mpsc::channel;
+use std::sync::Arc;
+use winit::dpi::LogicalSize;
+use winit::event::{Event, VirtualKeyCode};
+use winit::event_loop::{ControlFlow, EventLoop};
+use winit::window::WindowBuilder;
+use winit_input_helper::WinitInputHelper;
+
+/// Slowest and fastest speed of the worker sleeps.
+const SPEED_RANGE: (f64, f64) = (1.0 / 64.0, 64.0);
+
+/// Deepest zoom, as how many times the shown part is smaller than the whole frame.
+const MAX_ZOOM: usize = 64;
+
+/// Run the worker, showing everything it draws in a window. The status is drawn over the top left
+/// corner, like in the recordings.
+///
+/// Controls:
+/// * `Space` pauses and resumes the worker, `Right` lets the paused worker take a single step (up
+///   to the next sleep, or the next drawing event if it never sleeps).
+/// * `+` and `-` make the sleeps of the worker twice shorter or longer.
+/// * Mouse wheel zooms at the cursor, dragging with the left button pans, `0` resets the view.
+/// * The title shows the map position under the cursor.
+pub fn visualize(title: &str, worker_fn: impl FnOnce(Channel) + Send + 'static) {
+  let event_loop = EventLoop::new();
+  let mut input = WinitInputHelper::new();
+  let window = {
+    let size = LogicalSize::new(1024.0, 768.0);
+    WindowBuilder::new()
+      .with_title(title)
+      .with_inner_size(size)
+      .with_min_inner_size(size)
+      .build(&event_loop)
+      .unwrap()
+  };
+
+  let mut pixels = {
+    let window_size = window.inner_size();
+    let surface_texture = SurfaceTexture::new(window_size.width, window_size.height, &window);
+    Pixels::new(1, 1, surface_texture).unwrap()
+  };
+
+  let (sender, receiver) = channel();
+  let control = Arc::new(Control::new());
+  let channel = Channel::new(sender, true).with_control(control.clone());
+  std::thread::spawn(|| worker_fn(channel));
+
+  let mut frame = Frame::default();
+  let mut view = View::default();
+  let mut cursor = None;
+  let title = title.to_owned();
+  let mut shown_title = title.clone();
+  event_loop.run(move |event, _, control_flow| {
+    if let Event::MainEventsCleared = event {
+      let dims = frame.dims();
+      let mut changed = false;
+      while let Ok(user) = receiver.try_recv() {
+        changed |= frame.apply(user) != Change::None;
+      }
+      if frame.dims() != dims {
+        view = View::default();
+      }
+      if changed || view.dirty {
+        view.render(&frame, &mut pixels);
+      }
+      let new_title = window_title(&title, &control, cursor);
+      if new_title != shown_title {
+        window.set_title(&new_title);
+        shown_title = new_title;
+      }
+      if pixels.render().is_err() {
+        *control_flow = ControlFlow::Exit;
+        eprintln!("Render has failed.");
+        return;
+      }
+    }
+
+    // Handle input events
+    if input.update(&event) {
+      // Close events
+      if input.key_pressed(VirtualKeyCode::Escape) || input.quit() {
+        *control_flow = ControlFlow::Exit;
+        return;
+      }
+
+      // Resize the window
+      if let Some(size) = input.window_resized() {
+        pixels.resize_surface(size.width, size.height);
+      }
+
+      // Playback
+      if input.key_pressed(VirtualKeyCode::Space) {
+        control.update(|state| state.paused = !state.paused);
+      }
+      if input.key_pressed(VirtualKeyCode::Right) {
+        control.update(|state| {
+          state.paused = true;
+          state.steps += 1;
+        });
+      }
+      if input.key_pressed(VirtualKeyCode::Plus)
+        || input.key_pressed(VirtualKeyCode::Equals)
+        || input.key_pressed(VirtualKeyCode::NumpadAdd)
+      {
+        control.update(|state| state.speed = (state.speed * 2.0).min(SPEED_RANGE.1));
+      }
+      if input.key_pressed(VirtualKeyCode::Minus) || input.key_pressed(VirtualKeyCode::NumpadSubtract) {
+        control.update(|state| state.speed = (state.speed / 2.0).max(SPEED_RANGE.0));
+      }
+
+      // View
+      if input.key_pressed(VirtualKeyCode::Key0) {
+        view = View::default();
+      }
+      let pixel_at = |pos: (f32, f32)| pixels.window_pos_to_pixel(pos).ok();
+      let mouse = input.mouse();
+      let mouse_pixel = mouse.and_then(pixel_at);
+      cursor = mouse_pixel.map(|pixel| view.map_pos(&frame, pixel));
+      if let (Some(pixel), scroll) = (mouse_pixel, input.scroll_diff()) {
+        if scroll != 0.0 {
+          view.zoom_at(&frame, pixel, scroll > 0.0);
+        }
+      }
+      if let (Some((x, y)), true) = (mouse, input.mouse_held(0)) {
+        let (dx, dy) = input.mouse_diff();
+        if let (Some(from), Some(to)) = (pixel_at((x - dx, y - dy)), mouse_pixel) {
+          view.pan(
+            &frame,
+            Pos2::new(from.0 as isize - to.0 as isize, from.1 as isize - to.1 as isize),
+          );
+        }
+      }
+    }
+  });
+}
+
+fn window_title(title: &str, control: &Control, cursor: Option<Pos2>) -> String {
+  let mut result = title.to_owned();
+  if control.is_paused() {
+    result += " [paused]";
+  }
+  let speed = control.speed();
+  if speed != 1.0 {
+    result += &format!(" [x{}]", speed);
+  }
+  if let Some(pos) = cursor {
+    result += &format!(" {}", pos);
+  }
+  result
+}
+
+/// Part of the frame shown in the window.
+struct View {
+  /// How many times the shown part is smaller than the whole frame, a power of two.
+  zoom: usize,
+  /// Offset of the shown part from the top left corner of the frame.
+  offset: Pos2,
+  /// The view has changed since it was last rendered.
+  dirty: bool,
+}
+
+impl Default for View {
+  fn default() -> Self {
+    View {
+      zoom: 1,
+      offset: Pos2::zero(),
+      dirty: true,
+    }
+  }
+}
+
+impl View {
+  /// Width and height of the shown part.
+  fn dims(&self, frame: &Frame) -> (usize, usize) {
+    let (width, height) = frame.dims();
+    (width.div_ceil(self.zoom).max(1), height.div_ceil(self.zoom).max(1))
+  }
+
+  /// Map position shown at the pixel of the buffer.
+  fn map_pos(&self, frame: &Frame, (x, y): (usize, usize)) -> Pos2 {
+    frame.top_left() + self.offset + Pos2::new(x as isize, y as isize)
+  }
+
+  /// Keep the shown part within the frame.
+  fn clamp(&mut self, frame: &Frame) {
+    let (width, height) = frame.dims();
+    let (view_width, view_height) = self.dims(frame);
+    self.offset.x = self.offset.x.clamp(0, width.saturating_sub(view_width) as isize);
+    self.offset.y = self.offset.y.clamp(0, height.saturating_sub(view_height) as isize);
+    self.dirty = true;
+  }
+
+  /// Zoom in or out, keeping the map position under the pixel in place.
+  fn zoom_at(&mut self, frame: &Frame, (x, y): (usize, usize), zoom_in: bool) {
+    let pixel = Pos2::new(x as isize, y as isize);
+    let anchor = self.offset + pixel;
+    if zoom_in && self.zoom < MAX_ZOOM {
+      self.zoom *= 2;
+      self.offset = anchor - Pos2::new(pixel.x / 2, pixel.y / 2);
+    } else if !zoom_in && self.zoom > 1 {
+      self.zoom /= 2;
+      self.offset = anchor - Pos2::new(pixel.x * 2, pixel.y * 2);
+    }
+    self.clamp(frame);
+  }
+
+  fn pan(&mut self, frame: &Frame, delta: Pos2) {
+    if delta != Pos2::zero() {
+      self.offset += delta;
+      self.clamp(frame);
+    }
+  }
+
+  /// Copy the shown part of the frame into the buffer, resizing it if needed, and draw the status
+  /// over it.
+  fn render(&mut self, frame: &Frame, pixels: &mut Pixels) {
+    self.dirty = false;
+    let (width, _) = frame.dims();
+    let (view_width, view_height) = self.dims(frame);
+    let extent = pixels.context().texture_extent;
+    if (extent.width as usize, extent.height as usize) != (view_width, view_height) {
+      pixels.resize_buffer(view_width as u32, view_height as u32);
+    }
+    let rgba = frame.rgba();
+    let buffer = pixels.get_frame_mut();
+    if rgba.is_empty() {
+      return;
+    }
+    for row in 0..view_height {
+      let from = ((self.offset.y as usize + row) * width + self.offset.x as usize) * 4;
+      buffer[row * view_width * 4..(row + 1) * view_width * 4].copy_from_slice(&rgba[from..from + view_width * 4]);
+    }
+    if !frame.status().is_empty() {
+      draw_text(buffer, view_width, frame.status(), 1);
+    }
+  }
+}
@@ -0,0 +1,114 @@
+//! Regression tests running every day against every input file in its directory, checking the
+//! answers against the registry in `answers.json` next to the day directories.
+
+use aoc2022::days;
+use aoc2022::InputDir;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Expected {
+  part1: Option<String>,
+  part2: Option<String>,
+}
+
+/// Expected answers, keyed by the day directory (`dayNN`) and the input file name.
+type Registry = BTreeMap<String, BTreeMap<String, Expected>>;
+
+fn registry(inputs: &InputDir) -> Registry {
+  let path = inputs.root().join("answers.json");
+  let data = std::fs::read_to_string(&path).unwrap_or_else(|err| panic!("cannot read '{}': {}", path.display(), err));
+  serde_json::from_str(&data).unwrap_or_else(|err| panic!("invalid '{}': {}", path.display(), err))
+}
+
+fn check_part(failures: &mut Vec<String>, file: &str, part: usize, expected: &Option<String>, actual: String) {
+  if let Some(expected) = expected {
+    if *expected != actual {
+      failures.push(format!(
+        "{} part {}: expected '{}', got '{}'",
+        file, part, expected, actual
+      ));
+    }
+  }
+}
+
+fn check_day(day: usize) {
+  let inputs = InputDir::resolve(None);
+  let registry = registry(&inputs);
+  let solver = days::find(day).unwrap();
+  let answers = registry.get(&format!("day{:02}", day));
+  let files = inputs.files(day);
+
+  let mut failures = Vec::new();
+  for file in &files {
+    let Some(expected) = answers.and_then(|answers| answers.get(file)) else {
+      failures.push(format!("{}: no answers registered", file));
+      continue;
+    };
+    let input = inputs.load(day, file).unwrap_or_else(|err| panic!("{}", err));
+    let solution = match solver.parse(&input) {
+      Ok(solution) => solution,
+      Err(err) => {
+        failures.push(format!("{}: {}", file, err));
+        continue;
+      }
+    };
+    check_part(&mut failures, file, 1, &expected.part1, solution.part1());
+    check_part(&mut failures, file, 2, &expected.part2, solution.part2());
+  }
+  for file in answers.into_iter().flat_map(|answers| answers.keys()) {
+    if !files.contains(file) {
+      failures.push(format!("{}: answers registered, but there is no such input", file));
+    }
+  }
+  assert!(failures.is_empty(), "day{:02}:\n{}", day, failures.join("\n"));
+}
+
+#[test]
+fn registry_days() {
+  let registry = registry(&InputDir::resolve(None));
+  for key in registry.keys() {
+    let day = key.strip_prefix("day").and_then(|day| day.parse().ok());
+    assert!(day.and_then(days::find).is_some(), "no solution for '{}'", key);
+  }
+}
+
+macro_rules! day_tests {
+  ($($name:ident: $day:expr,)*) => {
+    $(
+      #[test]
+      fn $name() {
+        check_day($day);
+      }
+    )*
+  };
+}
+
+day_tests! {
+  day01: 1,
+  day02: 2,
+  day03: 3,
+  day04: 4,
+  day05: 5,
+  day06: 6,
+  day07: 7,
+  day08: 8,
+  day09: 9,
+  day10: 10,
+  day11: 11,
+  day12: 12,
+  day13: 13,
+  day14: 14,
+  day15: 15,
+  day16: 16,
+  day17: 17,
+  day18: 18,
+  day19: 19,
+  day20: 20,
+  day21: 21,
+  day22: 22,
+  day23: 23,
+  day24: 24,
+  day25: 25,
+}
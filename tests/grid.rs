@@ -0,0 +1,629 @@
+//! Grid algorithms of the library, on small hand-written maps.
+
+use aoc2022::{
+  chebyshev_to, manhattan_to, offset_moves, Area, Automaton, BoundsBehavior, CharMap, CubeNet, Dir2, Grid, Legend,
+  Pos2, SparseGrid, Template, MOVES_4, MOVES_4_STAY, MOVES_8, MOVES_KNIGHT,
+};
+
+#[test]
+fn map_and_zip() {
+  let map = CharMap::from_text("12\n34\n").with_bounds(BoundsBehavior::abyss(b'0'));
+  let digits = map.map(|ch| i32::from(ch - b'0'));
+  assert_eq!(digits[Pos2::new(1, 1)], 4);
+  assert_eq!(digits[Pos2::new(5, 5)], 0);
+
+  let sums = digits.zip(&map, |digit, ch| digit + i32::from(*ch));
+  assert_eq!(sums[Pos2::new(0, 1)], 3 + i32::from(b'3'));
+  assert_eq!(sums.dims(), Pos2::new(2, 2));
+}
+
+#[test]
+fn grow_generic() {
+  let mut grid = Grid::empty(BoundsBehavior::grow(Vec::<usize>::new()));
+  grid[Pos2::new(2, -1)].push(1);
+  grid[Pos2::new(-1, 1)].push(2);
+  assert_eq!(
+    (grid.top_left(), grid.bottom_right()),
+    (Pos2::new(-1, -1), Pos2::new(2, 1))
+  );
+  assert_eq!(grid[Pos2::new(2, -1)], vec![1]);
+  assert_eq!(grid[Pos2::new(-1, 1)], vec![2]);
+  assert_eq!(grid.count(Vec::new()), 10);
+}
+
+fn open_cost(map: &CharMap, _from: Pos2, to: Pos2) -> Option<usize> {
+  (map[to] != b'#').then_some(1)
+}
+
+#[test]
+fn find_path_route() {
+  let map = CharMap::from_text("S.#\n#.#\n..E\n");
+  let result = map
+    .find_path(Pos2::zero(), |map, pos| map[pos] == b'E', open_cost)
+    .unwrap();
+  assert_eq!(result.cost, 4);
+  assert_eq!(result.target, Pos2::new(2, 2));
+  assert_eq!(
+    result.path(),
+    vec![
+      Pos2::new(0, 0),
+      Pos2::new(1, 0),
+      Pos2::new(1, 1),
+      Pos2::new(1, 2),
+      Pos2::new(2, 2)
+    ]
+  );
+  assert_eq!(result.all_paths(), vec![result.path()]);
+
+  let walled = CharMap::from_text("S#E\n");
+  assert!(walled
+    .find_path(Pos2::zero(), |map, pos| map[pos] == b'E', open_cost)
+    .is_none());
+}
+
+#[test]
+fn find_path_all_shortest() {
+  let map = CharMap::from_text("...\n...\n");
+  let result = map
+    .find_path(Pos2::zero(), |_, pos| pos == Pos2::new(2, 1), open_cost)
+    .unwrap();
+  assert_eq!(result.cost, 3);
+  let paths = result.all_paths();
+  assert_eq!(paths.len(), 3);
+  for path in &paths {
+    assert_eq!(path.len(), 4);
+    assert_eq!((path[0], path[3]), (Pos2::zero(), Pos2::new(2, 1)));
+  }
+}
+
+#[test]
+fn astar_expands_less() {
+  let map = CharMap::from_text(&format!("{}\n", ".".repeat(20)).repeat(20));
+  let target = Pos2::new(19, 10);
+  let dijkstra = map
+    .find_path(Pos2::new(0, 10), |_, pos| pos == target, open_cost)
+    .unwrap();
+  let astar = map
+    .find_path_astar(
+      Pos2::new(0, 10),
+      offset_moves(&MOVES_4),
+      |_, pos| pos == target,
+      open_cost,
+      manhattan_to(target),
+    )
+    .unwrap();
+  assert_eq!((astar.cost, astar.target), (dijkstra.cost, dijkstra.target));
+  assert_eq!(astar.path().len(), 20);
+  assert!(astar.expanded < dijkstra.expanded / 4);
+
+  let walled = CharMap::from_text("S#E\n");
+  let target = Pos2::new(2, 0);
+  assert!(walled
+    .find_path_astar(
+      Pos2::zero(),
+      offset_moves(&MOVES_8),
+      |_, pos| pos == target,
+      open_cost,
+      chebyshev_to(target),
+    )
+    .is_none());
+}
+
+#[test]
+fn find_path_move_models() {
+  let map = CharMap::from_text("....\n....\n....\n....\n");
+  let target = Pos2::new(3, 3);
+  let path_cost = |moves: &[Dir2]| {
+    map
+      .find_path_moves(Pos2::zero(), offset_moves(moves), |_, pos| pos == target, open_cost)
+      .map(|result| result.cost)
+  };
+  assert_eq!(path_cost(&MOVES_4), Some(6));
+  assert_eq!(path_cost(&MOVES_4_STAY), Some(6));
+  assert_eq!(path_cost(&MOVES_8), Some(3));
+  assert_eq!(path_cost(&MOVES_KNIGHT), Some(2));
+  assert_eq!(path_cost(&[Dir2::new(2, 0)]), None);
+}
+
+#[test]
+fn find_path_sliding() {
+  // Sliding until hitting a wall or the edge of the map, like on ice
+  let map = CharMap::from_text("S..#\n....\n#...\n..#E\n");
+  let slide_moves = |map: &CharMap, pos: Pos2| {
+    MOVES_4
+      .iter()
+      .map(|dir| map.slide(pos, *dir, |map, next| map[next] == b'#'))
+      .filter(|next| *next != pos)
+      .collect::<Vec<_>>()
+  };
+  let result = map
+    .find_path_moves(Pos2::zero(), slide_moves, |map, pos| map[pos] == b'E', open_cost)
+    .unwrap();
+  assert_eq!(
+    result.path(),
+    vec![Pos2::new(0, 0), Pos2::new(0, 1), Pos2::new(3, 1), Pos2::new(3, 3)]
+  );
+}
+
+#[test]
+fn wrap_torus() {
+  let mut map = CharMap::from_text("S#..E\nab...\n").with_bounds(BoundsBehavior::Wrap);
+  assert_eq!(map[Pos2::new(5, 1)], b'a');
+  assert_eq!(map[Pos2::new(-1, -1)], b'.');
+  map[Pos2::new(6, 3)] = b'x';
+  assert_eq!(map[Pos2::new(1, 1)], b'x');
+  assert_eq!(map.neighbor(Pos2::zero(), Dir2::new(-1, 0)), Some(Pos2::new(4, 0)));
+  assert_eq!(map.neighbors(Pos2::zero()).count(), 4);
+
+  let result = map
+    .find_path(Pos2::zero(), |map, pos| map[pos] == b'E', open_cost)
+    .unwrap();
+  assert_eq!(result.path(), vec![Pos2::new(0, 0), Pos2::new(4, 0)]);
+  assert_eq!(
+    map.cast_find(Pos2::zero(), Dir2::new(1, 0), |map, pos| map[pos] == b'S'),
+    None
+  );
+}
+
+#[test]
+fn wrap_non_blank() {
+  let mut map = CharMap::from_text("  ...\n  .#.\n.....\n  .\n").with_bounds(BoundsBehavior::wrap_non_blank(b' '));
+  map[Pos2::new(10, 10)] = b'#';
+  assert_eq!(map[Pos2::new(10, 10)], b' ');
+  assert_eq!(map.neighbor(Pos2::new(3, 0), Dir2::new(1, 0)), Some(Pos2::new(4, 0)));
+  assert_eq!(map.neighbor(Pos2::new(2, 0), Dir2::new(-1, 0)), Some(Pos2::new(4, 0)));
+  assert_eq!(map.neighbor(Pos2::new(2, 3), Dir2::new(0, 1)), Some(Pos2::new(2, 0)));
+  assert_eq!(map.neighbor(Pos2::new(4, 2), Dir2::new(0, 1)), Some(Pos2::new(4, 0)));
+  assert_eq!(map.neighbor(Pos2::new(0, 2), Dir2::new(0, -1)), Some(Pos2::new(0, 2)));
+  assert_eq!(map.neighbor(Pos2::new(4, 1), Dir2::new(0, 2)), Some(Pos2::new(4, 0)));
+  assert_eq!(map.neighbor(Pos2::new(3, 2), Dir2::new(-2, 2)), Some(Pos2::new(4, 1)));
+
+  let wall = |map: &CharMap, pos: Pos2| map[pos] == b'#';
+  assert_eq!(
+    map.cast_find(Pos2::new(4, 1), Dir2::new(1, 0), wall),
+    Some(Pos2::new(3, 1))
+  );
+  assert_eq!(map.cast_find(Pos2::new(0, 2), Dir2::new(1, 0), wall), None);
+  assert_eq!(map.slide(Pos2::new(3, 2), Dir2::new(0, 1), wall), Pos2::new(3, 0));
+}
+
+/// All 11 nets of the cube, one character per face.
+const CUBE_NETS: [&str; 11] = [
+  "x\nxxxx\nx\n",
+  "x\nxxxx\n x\n",
+  "x\nxxxx\n  x\n",
+  "x\nxxxx\n   x\n",
+  " x\nxxxx\n x\n",
+  " x\nxxxx\n  x\n",
+  "xx\n xxx\n x\n",
+  "xx\n xxx\n  x\n",
+  "xx\n xxx\n   x\n",
+  "xx\n xx\n  xx\n",
+  "xxx\n  xxx\n",
+];
+
+fn scale_net(net: &str, size: usize) -> String {
+  let mut text = String::new();
+  for line in net.lines() {
+    let row: String = line.chars().flat_map(|ch| std::iter::repeat_n(ch, size)).collect();
+    for _ in 0..size {
+      text.push_str(&row);
+      text.push('\n');
+    }
+  }
+  text
+}
+
+#[test]
+fn cube_nets() {
+  for net in CUBE_NETS {
+    let map = CharMap::from_text(&scale_net(net, 3));
+    for map in map.symmetries() {
+      let cube = CubeNet::fold(&map, b' ').unwrap();
+      assert_eq!(cube.size(), 3);
+      assert_eq!(cube.faces().count(), 6);
+      // 14 edges of the net are glued together
+      assert_eq!(cube.wraps().len(), 14 * 3, "{}", map);
+      for (&(pos, dir), &(next, next_dir)) in cube.wraps() {
+        assert_eq!(cube.step(next, -next_dir), (pos, -dir), "{}", map);
+      }
+      // Walking straight around the cube gets back to the start
+      for pos in map.every_pos().filter(|pos| map[*pos] != b' ') {
+        for dir in MOVES_4 {
+          let (end, end_dir) = (0..4 * 3).fold((pos, dir), |(pos, dir), _| cube.step(pos, dir));
+          assert_eq!((end, end_dir), (pos, dir), "{}", map);
+        }
+      }
+    }
+
+    // The mirrored net folds into the mirrored cube, gluing the mirrored edges
+    let cube = CubeNet::fold(&map, b' ').unwrap();
+    let flipped = CubeNet::fold(&map.flip_horizontal(), b' ').unwrap();
+    let width = map.dims().x;
+    let mirror = |(pos, dir): (Pos2, Dir2)| (Pos2::new(width - 1 - pos.x, pos.y), Dir2::new(-dir.dx, dir.dy));
+    for (&from, &to) in cube.wraps() {
+      assert_eq!(flipped.wraps()[&mirror(from)], mirror(to), "{}", map);
+    }
+  }
+}
+
+#[test]
+fn cube_net_example() {
+  let map = CharMap::from_text(&scale_net("  x\nxxx\n  xx\n", 4));
+  let cube = CubeNet::fold(&map, b' ').unwrap();
+  assert_eq!(
+    cube.faces().collect::<Vec<_>>(),
+    vec![
+      Pos2::new(8, 0),
+      Pos2::new(0, 4),
+      Pos2::new(4, 4),
+      Pos2::new(8, 4),
+      Pos2::new(8, 8),
+      Pos2::new(12, 8)
+    ]
+  );
+  assert_eq!(
+    cube.step(Pos2::new(11, 5), Dir2::new(1, 0)),
+    (Pos2::new(14, 8), Dir2::new(0, 1))
+  );
+  assert_eq!(
+    cube.step(Pos2::new(10, 11), Dir2::new(0, 1)),
+    (Pos2::new(1, 7), Dir2::new(0, -1))
+  );
+
+  assert!(CubeNet::fold(&CharMap::from_text("xxxxxx\n"), b' ').is_err());
+  assert!(CubeNet::fold(&CharMap::from_text("xx\nxx\n"), b' ').is_err());
+  assert!(CubeNet::fold(&CharMap::from_text(&scale_net("xxxxx\nx\n", 2)), b' ').is_err());
+}
+
+#[test]
+fn visibility() {
+  let map = CharMap::from_text("30373\n25512\n65332\n33549\n35390\n");
+  let visibility = map.visibility(|tree, other| other >= tree);
+  assert_eq!(map.every_pos().filter(|pos| visibility.is_visible(*pos)).count(), 21);
+  let pos = Pos2::new(2, 1);
+  assert!(visibility.is_visible_from(pos, Dir2::new(0, -1)));
+  assert!(visibility.is_visible_from(pos, Dir2::new(1, 0)));
+  assert!(!visibility.is_visible_from(pos, Dir2::new(-1, 0)));
+  assert_eq!(visibility.views[pos], [2, 2, 1, 1]);
+  assert_eq!(visibility.views[Pos2::new(2, 3)], [2, 1, 2, 2]);
+  assert_eq!(visibility.view_distance(Pos2::zero(), Dir2::new(-1, 0)), 0);
+
+  // Seeing over the cells lower than the viewer only
+  let visibility = map.visibility(|tree, other| other > tree);
+  assert_eq!(visibility.views[pos], [2, 3, 2, 1]);
+}
+
+#[test]
+fn find_template() {
+  let map = CharMap::from_text(".#.....\n###..#.\n.#...##\n.....#.\n");
+  let plus = Template::from_text(" # \n###\n # \n", b' ');
+  assert_eq!(map.find_template(&plus), vec![Pos2::new(0, 0)]);
+  assert_eq!(plus.symmetries().len(), 1);
+
+  // Missing characters at the end of the rows are wildcards too
+  let tee = Template::from_text("#\n##\n#\n", b' ');
+  assert_eq!(tee.dims(), Pos2::new(2, 3));
+  assert_eq!(map.find_template(&tee), vec![Pos2::new(1, 0), Pos2::new(5, 1)]);
+  assert_eq!(tee.symmetries().len(), 4);
+  let mut found: Vec<_> = map
+    .find_template_symmetric(&tee)
+    .into_iter()
+    .map(|(_, pos)| pos)
+    .collect();
+  found.sort_by_key(|pos| (pos.y, pos.x));
+  assert_eq!(
+    found,
+    vec![
+      Pos2::new(0, 0),
+      Pos2::new(0, 0),
+      Pos2::new(1, 0),
+      Pos2::new(0, 1),
+      Pos2::new(5, 1)
+    ]
+  );
+
+  let dots = Template::from_text("?.?\n...\n", b'?');
+  assert_eq!(
+    map.find_template(&dots),
+    vec![Pos2::new(2, 1), Pos2::new(1, 2), Pos2::new(2, 2)]
+  );
+  assert!(map.find_template(&Template::from_text("........\n", b'?')).is_empty());
+
+  // Empty templates match nowhere
+  assert!(map.find_template(&Template::from_text("", b'?')).is_empty());
+  assert!(map.find_template(&Template::from_text("\n\n", b'?')).is_empty());
+  assert!(map
+    .find_template_symmetric(&Template::from_text("\n\n", b'?'))
+    .is_empty());
+}
+
+#[test]
+fn legend() {
+  #[derive(Clone, Copy, PartialEq, Debug)]
+  enum Cell {
+    Open,
+    Wall,
+  }
+  #[derive(Clone, PartialEq, Debug)]
+  enum Entity {
+    Start,
+    Wind(Dir2),
+  }
+  let legend = Legend::new()
+    .cell(b'.', Cell::Open)
+    .cell(b'#', Cell::Wall)
+    .cell(b' ', Cell::Wall)
+    .entity(b'S', Entity::Start, Cell::Open)
+    .entity(b'>', Entity::Wind(Dir2::new(1, 0)), Cell::Open)
+    .entity(b'<', Entity::Wind(Dir2::new(-1, 0)), Cell::Open);
+
+  let parsed = legend.parse("#S.>\n#<\n").unwrap();
+  assert_eq!(parsed.cells.dims(), Pos2::new(4, 2));
+  assert_eq!(parsed.cells[Pos2::new(1, 0)], Cell::Open);
+  assert_eq!(parsed.cells[Pos2::new(3, 1)], Cell::Wall);
+  assert_eq!(
+    parsed.entities,
+    vec![
+      (Pos2::new(1, 0), Entity::Start),
+      (Pos2::new(3, 0), Entity::Wind(Dir2::new(1, 0))),
+      (Pos2::new(1, 1), Entity::Wind(Dir2::new(-1, 0))),
+    ]
+  );
+  assert_eq!(parsed.single(&Entity::Start, "start"), Ok(Pos2::new(1, 0)));
+  let winds = parsed
+    .entities
+    .iter()
+    .filter(|(_, entity)| matches!(entity, Entity::Wind(_)));
+  assert_eq!(winds.count(), 2);
+
+  let err = legend.parse("#S.\n#.x\n").err().unwrap();
+  assert_eq!((err.line, err.span), (Some(2), Some(2..3)));
+  // Short rows are padded with spaces, which have to be in the legend
+  let parsed = legend.parse("#..\n#\n").unwrap();
+  assert_eq!(parsed.cells[Pos2::new(2, 1)], Cell::Wall);
+  let ragged = Legend::new().cell(b'.', ()).entity(b' ', Entity::Start, ());
+  let parsed = ragged.parse("...\n.\n").unwrap();
+  assert_eq!(
+    parsed.positions(&Entity::Start).collect::<Vec<_>>(),
+    vec![Pos2::new(1, 1), Pos2::new(2, 1)]
+  );
+  let err = Legend::<(), Entity>::new()
+    .cell(b'.', ())
+    .parse("...\n.\n")
+    .err()
+    .unwrap();
+  assert_eq!(err.line, Some(2));
+  assert!(err.message.contains("shorter"), "{}", err);
+
+  let parsed = legend.parse("S.S\n").unwrap();
+  assert!(parsed.single(&Entity::Start, "start").is_err());
+  assert!(parsed.single(&Entity::Wind(Dir2::new(1, 0)), "wind").is_err());
+  assert_eq!(parsed.positions(&Entity::Start).count(), 2);
+}
+
+#[test]
+fn distance_field_multi_source() {
+  let map = CharMap::from_text("A..#.\n.#...\n...#B\n");
+  let sources = map.every_pos().filter(|pos| map[*pos].is_ascii_uppercase());
+  let field = map.distance_field_bfs(sources, offset_moves(&MOVES_4), |map, _, to| map[to] != b'#');
+  assert_eq!(field.distance(Pos2::new(0, 0)), Some(0));
+  assert_eq!(field.distance(Pos2::new(2, 0)), Some(2));
+  assert_eq!(field.distance(Pos2::new(4, 0)), Some(2));
+  assert_eq!(field.distance(Pos2::new(2, 2)), Some(4));
+  assert_eq!(field.distance(Pos2::new(3, 0)), None);
+  assert_eq!(
+    field.path_to(Pos2::new(4, 0)),
+    Some(vec![Pos2::new(4, 2), Pos2::new(4, 1), Pos2::new(4, 0)])
+  );
+
+  let weighted = map.distance_field([Pos2::zero()], offset_moves(&MOVES_4), |map, _, to| match map[to] {
+    b'#' => None,
+    b'.' => Some(1),
+    _ => Some(10),
+  });
+  assert_eq!(weighted.distance(Pos2::new(4, 2)), Some(15));
+  assert_eq!(weighted.distance(Pos2::new(4, 0)), Some(6));
+  let rendered = weighted.distances.map(|distance| match distance {
+    Some(distance) => b'0' + (*distance % 10) as u8,
+    None => b'#',
+  });
+  assert_eq!(rendered.to_string(), "012#6\n1#345\n234#5\n");
+}
+
+#[test]
+fn flood_fill_and_components() {
+  let map = CharMap::from_text("##..#\n#..##\n..#..\n");
+  let filled = map.flood_fill(Pos2::new(2, 0), |map, pos| map[pos] == b'.');
+  assert_eq!(filled.len(), 6);
+  assert_eq!(filled[0], Pos2::new(2, 0));
+  assert!(!filled.contains(&Pos2::new(3, 2)));
+  assert!(map.flood_fill(Pos2::zero(), |map, pos| map[pos] == b'.').is_empty());
+
+  let walls = map.components(|ch| (*ch == b'#').then_some(()));
+  assert_eq!(walls.len(), 3);
+  let sizes = walls.components.iter().map(|c| c.size).collect::<Vec<_>>();
+  assert_eq!(sizes, vec![3, 3, 1]);
+  assert_eq!(walls.components[0].perimeter, 8);
+  assert_eq!(walls.components[2].perimeter, 4);
+  assert_eq!(
+    walls.component(Pos2::new(3, 1)).unwrap().area,
+    Area::new(Pos2::new(3, 0), Pos2::new(4, 1))
+  );
+  assert_eq!(walls.labels[Pos2::new(2, 0)], None);
+
+  let all = map.components(|ch| Some(*ch));
+  assert_eq!(all.len(), 5);
+  assert_eq!(all.components.iter().map(|c| c.size).sum::<usize>(), 15);
+}
+
+#[test]
+fn grow_in_every_direction() {
+  let mut map = CharMap::empty(BoundsBehavior::grow(b'.'));
+  let mut written = Vec::new();
+  let mut state = 0x1234_5678_u64;
+  for idx in 0..500 {
+    state ^= state << 13;
+    state ^= state >> 7;
+    state ^= state << 17;
+    let pos = Pos2::new((state % 200) as isize - 100, ((state >> 20) % 200) as isize - 100);
+    let ch = b'a' + (idx % 26) as u8;
+    map[pos] = ch;
+    written.retain(|(other, _)| *other != pos);
+    written.push((pos, ch));
+  }
+  for (pos, ch) in &written {
+    assert_eq!(map[*pos], *ch);
+  }
+  let (top_left, bottom_right) = (map.top_left(), map.bottom_right());
+  assert_eq!(top_left.x, written.iter().map(|(pos, _)| pos.x).min().unwrap());
+  assert_eq!(bottom_right.y, written.iter().map(|(pos, _)| pos.y).max().unwrap());
+  let dims = map.dims();
+  assert_eq!(map.count(b'.'), (dims.x * dims.y) as usize - written.len());
+
+  // Cells added later get the current default
+  let mut map = map.with_bounds(BoundsBehavior::grow(b' '));
+  map[bottom_right + Pos2::new(1, 1)] = b'#';
+  assert_eq!(map[bottom_right + Pos2::new(1, 0)], b' ');
+  assert_eq!(map[Pos2::new(top_left.x, bottom_right.y + 1)], b' ');
+}
+
+#[test]
+fn sparse_grid() {
+  let mut grid = SparseGrid::new(b'.');
+  assert_eq!(grid.bounding_box(), None);
+  assert_eq!(grid.every_pos().count(), 0);
+  grid[Pos2::new(-100, 3)] = b'#';
+  grid[Pos2::new(1_000_000, -20)] = b'#';
+  grid[Pos2::new(5, 5)] = b'#';
+  assert_eq!(grid[Pos2::new(-100, 3)], b'#');
+  assert_eq!(grid[Pos2::new(-99, 3)], b'.');
+  assert_eq!(grid[Pos2::new(17, 17)], b'.');
+  let mut occupied = grid.occupied().collect::<Vec<_>>();
+  occupied.sort();
+  assert_eq!(
+    occupied,
+    vec![Pos2::new(-100, 3), Pos2::new(5, 5), Pos2::new(1_000_000, -20)]
+  );
+  assert_eq!(
+    grid.bounding_box(),
+    Some(Area::new(Pos2::new(-100, -20), Pos2::new(1_000_000, 5)))
+  );
+
+  grid[Pos2::new(1_000_000, -20)] = b'.';
+  grid.shrink();
+  let area = Area::new(Pos2::new(-100, 3), Pos2::new(5, 5));
+  assert_eq!(grid.bounding_box(), Some(area));
+  assert_eq!(grid.every_pos().count(), 106 * 3);
+  let map = grid.to_grid();
+  assert_eq!((map.top_left(), map.bottom_right()), (area.top_left, area.bottom_right));
+  assert_eq!(map[Pos2::new(5, 5)], b'#');
+  assert_eq!(map.count(b'#'), 2);
+}
+
+#[test]
+fn transforms() {
+  let map = CharMap::from_text("abc\ndef\n");
+  assert_eq!(map.rotate(1).to_string(), "da\neb\nfc\n");
+  assert_eq!(map.rotate(2).to_string(), "fed\ncba\n");
+  assert_eq!(map.rotate(-1).to_string(), "cf\nbe\nad\n");
+  assert_eq!(map.rotate(4).to_string(), map.to_string());
+  assert_eq!(map.flip_horizontal().to_string(), "cba\nfed\n");
+  assert_eq!(map.flip_vertical().to_string(), "def\nabc\n");
+  assert_eq!(map.transpose().to_string(), "ad\nbe\ncf\n");
+
+  let symmetries = map.symmetries();
+  assert_eq!(symmetries.len(), 8);
+  for (idx, symmetry) in symmetries.iter().enumerate() {
+    assert!(map.same_cells_symmetric(symmetry));
+    for other in &symmetries[idx + 1..] {
+      assert!(!symmetry.same_cells(other));
+    }
+  }
+  assert!(!map.same_cells_symmetric(&CharMap::from_text("abc\nfed\n")));
+}
+
+#[test]
+fn crop_and_paste() {
+  let map = CharMap::from_text("abcd\nefgh\nijkl\n").with_bounds(BoundsBehavior::abyss(b' '));
+  let area = Area::new(Pos2::new(2, 1), Pos2::new(4, 2));
+  let cropped = map.crop(area);
+  assert_eq!(
+    (cropped.top_left(), cropped.bottom_right()),
+    (area.top_left, area.bottom_right)
+  );
+  assert_eq!(cropped.to_string(), "gh \nkl \n");
+  assert_eq!(cropped[Pos2::new(2, 1)], b'g');
+
+  let mut target = CharMap::empty(BoundsBehavior::grow(b'.'));
+  target[Pos2::zero()] = b'#';
+  target.paste(&cropped.rotate(1), Pos2::new(-2, 1));
+  assert_eq!(target.to_string(), "..#\nkg.\nlh.\n  .\n");
+  assert!(target
+    .crop(Area::new(Pos2::new(-2, 1), Pos2::new(-1, 3)))
+    .same_cells(&cropped.rotate(1)));
+}
+
+fn life(map: &CharMap, pos: Pos2) -> u8 {
+  let alive = MOVES_8.iter().filter(|dir| map[pos + **dir] == b'#').count();
+  match (map[pos], alive) {
+    (b'#', 2) | (_, 3) => b'#',
+    _ => b'.',
+  }
+}
+
+#[test]
+fn step_update_offset_and_grown() {
+  let mut map = CharMap::empty(BoundsBehavior::grow(b'.'));
+  for x in 10..13 {
+    map[Pos2::new(x, -5)] = b'#';
+  }
+  map[Pos2::new(8, -7)] = b'.';
+  map[Pos2::new(14, -3)] = b'.';
+  assert!(map.step_update(life));
+  assert_eq!(map.to_string(), ".......\n...#...\n...#...\n...#...\n.......\n");
+  assert!(map.step_update(life));
+  assert_eq!(map.to_string(), ".......\n.......\n..###..\n.......\n.......\n");
+}
+
+#[test]
+fn automaton_modes() {
+  let glider = CharMap::from_text(".#......\n..#.....\n###.....\n........\n........\n........\n")
+    .with_bounds(BoundsBehavior::abyss(b'.'))
+    .with_offset(Pos2::new(-3, 7));
+  let mut full = Automaton::new(glider.clone(), life);
+  let mut active = Automaton::new(glider, life).with_active_cells(&MOVES_8);
+  for _ in 0..12 {
+    assert_eq!(full.step(), active.step());
+    assert!(full.grid().same_cells(active.grid()));
+  }
+  assert_eq!(full.generation(), 12);
+  // The glider crashes into the bottom edge and turns into a block
+  assert_eq!(active.run_until_stable(), 3);
+  assert_eq!(active.grid().count(b'#'), 4);
+  assert_eq!(active.run(10), 0);
+
+  // On a torus the glider keeps flying across the edges
+  let glider = CharMap::from_text(".#....\n..#...\n###...\n......\n......\n......\n").with_bounds(BoundsBehavior::Wrap);
+  let mut full = Automaton::new(glider.clone(), life);
+  let mut active = Automaton::new(glider.clone(), life).with_active_cells(&MOVES_8);
+  for _ in 0..24 {
+    assert!(full.step() && active.step());
+    assert!(full.grid().same_cells(active.grid()));
+  }
+  // Back to the start after 4 steps for every cell it moved diagonally
+  assert!(active.grid().same_cells(&glider));
+}
+
+#[test]
+fn automaton_repeat() {
+  let blinker = CharMap::from_text(".....\n.....\n.###.\n.....\n.....\n").with_bounds(BoundsBehavior::abyss(b'.'));
+  let mut automaton = Automaton::new(blinker, life)
+    .with_active_cells(&MOVES_8)
+    .with_repeat_detection();
+  assert_eq!(automaton.run(5), 5);
+  let repeat = automaton.repeat().unwrap();
+  assert_eq!((repeat.first, repeat.period), (0, 2));
+  assert_eq!(repeat.equivalent(1_000_001), 1);
+  assert_eq!(automaton.run_until_stable(), 0);
+}
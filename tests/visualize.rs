@@ -0,0 +1,172 @@
+//! Headless visualization backends, driven by the days drawing their progress or by hand.
+
+use aoc2022::days;
+use aoc2022::visualize::{record_log, Channel, EventLog, FrameTrigger, RecordFormat, Recorder};
+use aoc2022::{input_data, Area, Poly, Pos2};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+fn temp_dir(name: &str) -> PathBuf {
+  let dir = std::env::temp_dir().join(format!("aoc2022-{}-{}", name, std::process::id()));
+  let _ = std::fs::remove_dir_all(&dir);
+  dir
+}
+
+#[test]
+fn record_png_sequence() {
+  let dir = temp_dir("record-png");
+  let data = input_data(14, "test.txt").unwrap();
+  let frames = Recorder::new(RecordFormat::PngSequence(dir.clone()))
+    .with_trigger(FrameTrigger::Events(50))
+    .with_scale(2)
+    .record(move |channel| {
+      let solution = days::find(14).unwrap().parse(&data).unwrap();
+      assert_eq!(solution.visualize(&channel), ("24".to_owned(), "93".to_owned()));
+    })
+    .unwrap();
+  assert!(frames > 1);
+  assert_eq!(std::fs::read_dir(&dir).unwrap().count(), frames);
+  let last = std::fs::read(dir.join(format!("frame{:05}.png", frames - 1))).unwrap();
+  assert!(last.starts_with(b"\x89PNG"));
+  std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn record_gif() {
+  let dir = temp_dir("record-gif");
+  std::fs::create_dir_all(&dir).unwrap();
+  let path = dir.join("diagonal.gif");
+  let format = RecordFormat::from_path(&path);
+  assert_eq!(format, RecordFormat::Gif(path.clone()));
+  let frames = Recorder::new(format)
+    .with_trigger(FrameTrigger::Sleeps(10))
+    .record(|channel| {
+      channel.draw_init(Pos2::new(0, 0), Pos2::new(9, 9), |_| (0, 0, 0));
+      for x in 0..10 {
+        channel.draw_map_pixel(Pos2::new(x, x), (0xff, 0, 0));
+        channel.sleep(Duration::from_millis(10));
+      }
+    })
+    .unwrap();
+  assert_eq!(frames, 1);
+  assert!(std::fs::read(&path).unwrap().starts_with(b"GIF89a"));
+  std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn record_status_before_init() {
+  let dir = temp_dir("status-before-init");
+  let frames = Recorder::new(RecordFormat::PngSequence(dir.clone()))
+    .record(|channel| {
+      channel.set_status("starting");
+      channel.sleep(Duration::from_millis(10));
+      channel.draw_init(Pos2::new(0, 0), Pos2::new(3, 3), |_| (0, 0, 0));
+      channel.sleep(Duration::from_millis(10));
+    })
+    .unwrap();
+  assert_eq!(frames, 1);
+  assert_eq!(std::fs::read_dir(&dir).unwrap().count(), 1);
+  std::fs::remove_dir_all(&dir).unwrap();
+}
+
+fn record_pngs(dir: &Path, worker_fn: impl FnOnce(Channel) + Send + 'static) -> Vec<Vec<u8>> {
+  let frames = Recorder::new(RecordFormat::PngSequence(dir.to_path_buf()))
+    .with_trigger(FrameTrigger::Events(20))
+    .record(worker_fn)
+    .unwrap();
+  (0..frames)
+    .map(|idx| std::fs::read(dir.join(format!("frame{:05}.png", idx))).unwrap())
+    .collect()
+}
+
+#[test]
+fn replay_log() {
+  let dir = temp_dir("replay-log");
+  std::fs::create_dir_all(&dir).unwrap();
+  let path = dir.join("day14.log");
+  let data = input_data(14, "test.txt").unwrap();
+  let worker = move |channel: Channel| {
+    let solution = days::find(14).unwrap().parse(&data).unwrap();
+    solution.visualize(&channel);
+    channel.sleep(Duration::from_millis(100));
+  };
+  let events = record_log(&path, worker.clone()).unwrap();
+  let log = EventLog::load(&path).unwrap();
+  assert_eq!(log.len(), events);
+  assert_eq!(log.duration(), Duration::from_millis(100));
+
+  let direct = record_pngs(&dir.join("direct"), worker);
+  let replayed = {
+    let log = log.clone();
+    record_pngs(&dir.join("replayed"), move |channel| {
+      log.replay(&channel, 2.0, Duration::ZERO)
+    })
+  };
+  assert_eq!(direct, replayed);
+  // Seeking to the end draws the final state at once
+  let seeked = record_pngs(&dir.join("seeked"), move |channel| {
+    log.replay(&channel, 1.0, Duration::from_secs(1))
+  });
+  assert_eq!(seeked.len(), 1);
+  assert_eq!(seeked[0], direct[direct.len() - 1]);
+  std::fs::remove_dir_all(&dir).unwrap();
+}
+
+fn decode_png(data: &[u8]) -> (usize, Vec<u8>) {
+  let mut reader = png::Decoder::new(data).read_info().unwrap();
+  let mut rgba = vec![0; reader.output_buffer_size()];
+  let info = reader.next_frame(&mut rgba).unwrap();
+  (info.width as usize, rgba)
+}
+
+#[test]
+fn drawing_primitives() {
+  let dir = temp_dir("primitives");
+  std::fs::create_dir_all(&dir).unwrap();
+  let path = dir.join("primitives.log");
+  let worker = |channel: Channel| {
+    channel.draw_init(Pos2::new(-5, -5), Pos2::new(14, 24), |_| (0, 0, 0xff));
+    channel.clear((0, 0, 0));
+    channel.set_palette(&[(0xff, 0, 0), (0, 0xff, 0)]);
+    channel.fill_rect(Area::new(Pos2::new(12, 20), Pos2::new(13, 21)), 1);
+    channel.draw_line(Pos2::new(0, 10), Pos2::new(9, 13), (0xff, 0xff, 0xff));
+    channel.draw_poly(
+      &Poly::new(vec![Pos2::new(0, 15), Pos2::new(4, 19), Pos2::new(0, 19)]),
+      0,
+    );
+    channel.draw_map_pixel(Pos2::new(14, 24), 7);
+    channel.set_status("cost: 42");
+  };
+  record_log(&path, worker).unwrap();
+  let log = EventLog::load(&path).unwrap();
+  assert_eq!(log.len(), 8);
+  let frames = record_pngs(&dir.join("frames"), move |channel| {
+    log.replay(&channel, 1.0, Duration::ZERO)
+  });
+  let (width, rgba) = decode_png(&frames[frames.len() - 1]);
+  let pixel = |x: isize, y: isize| {
+    let idx = (((y + 5) as usize) * width + (x + 5) as usize) * 4;
+    (rgba[idx], rgba[idx + 1], rgba[idx + 2])
+  };
+  // Status is drawn over the top left corner, in white on black
+  assert_eq!(pixel(-5, -5), (0, 0, 0));
+  assert_eq!(pixel(-3, -4), (0xff, 0xff, 0xff));
+  // Filled rectangle in the palette color
+  for pos in Pos2::iter_rect(Pos2::new(12, 20), Pos2::new(13, 21)) {
+    assert_eq!(pixel(pos.x, pos.y), (0, 0xff, 0));
+  }
+  assert_eq!(pixel(11, 20), (0, 0, 0));
+  // Line of any slope, both ends included
+  for (x, y) in [(0, 10), (1, 10), (2, 11), (3, 11), (5, 12), (8, 13), (9, 13)] {
+    assert_eq!(pixel(x, y), (0xff, 0xff, 0xff), "({}, {})", x, y);
+  }
+  assert_eq!(pixel(9, 10), (0, 0, 0));
+  // Closed polygon outline in the palette color
+  for (x, y) in [(0, 15), (2, 17), (4, 19), (2, 19), (0, 17)] {
+    assert_eq!(pixel(x, y), (0xff, 0, 0), "({}, {})", x, y);
+  }
+  assert_eq!(pixel(1, 18), (0, 0, 0));
+  // Colors not in the palette are black
+  assert_eq!(pixel(14, 24), (0, 0, 0));
+  std::fs::remove_dir_all(&dir).unwrap();
+}